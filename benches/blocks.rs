@@ -0,0 +1,44 @@
+//! Compare the streaming and borrowed block splitters on multi-megabyte synthetic inputs
+use advent_of_code::{block_slices, blocks};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use std::fmt::Write;
+use std::io::BufReader;
+
+/// Generate calorie counts in the style of day 1, with `lines_per_block` items per elf, until the
+/// input reaches at least `size` bytes.
+fn synthetic_input(size: usize, lines_per_block: usize) -> String {
+    let mut result = String::with_capacity(size + 16);
+    let mut item = 0u32;
+    while result.len() < size {
+        for _ in 0..lines_per_block {
+            item = item.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            writeln!(result, "{}", item % 100_000).unwrap();
+        }
+        result.push('\n');
+    }
+    result
+}
+
+fn split_blocks(c: &mut Criterion) {
+    let size = 8 * 1024 * 1024;
+    let mut group = c.benchmark_group("blocks");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(size as u64));
+    for lines_per_block in [8, 1_000_000] {
+        let input = synthetic_input(size, lines_per_block);
+        group.bench_with_input(
+            BenchmarkId::new("streaming", lines_per_block),
+            &input,
+            |b, input| b.iter(|| blocks(BufReader::new(input.as_bytes())).count()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("borrowed", lines_per_block),
+            &input,
+            |b, input| b.iter(|| block_slices(input).count()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, split_blocks);
+criterion_main!(benches);
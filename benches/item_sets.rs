@@ -0,0 +1,65 @@
+//! Compare bit mask item sets with hash sets for day 3 on a large generated input
+use advent_of_code::random::Random;
+use advent_of_code::y2022::day03::RucksackReorganization;
+use advent_of_code::y2022::generators;
+use advent_of_code::Solution;
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use std::collections::HashSet;
+
+/// Both parts of day 3 computed with a `HashSet<char>` per compartment and per rucksack, as the
+/// solution did before item sets were introduced
+fn solve_with_hash_sets(input: &str) -> (u32, u32) {
+    let priority = |item: char| match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        _ => item as u32 - 'A' as u32 + 27,
+    };
+    let rucksacks = input
+        .lines()
+        .map(|line| {
+            let (left, right) = line.split_at(line.len() / 2);
+            (
+                left.chars().collect::<HashSet<char>>(),
+                right.chars().collect::<HashSet<char>>(),
+                line.chars().collect::<HashSet<char>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let part1 = rucksacks
+        .iter()
+        .map(|(left, right, _)| priority(*left.intersection(right).next().unwrap()))
+        .sum();
+    let part2 = rucksacks
+        .chunks(3)
+        .map(|group| {
+            let common = group[0]
+                .2
+                .intersection(&group[1].2)
+                .copied()
+                .collect::<HashSet<char>>();
+            priority(*common.intersection(&group[2].2).next().unwrap())
+        })
+        .sum();
+    (part1, part2)
+}
+
+fn solve_with_item_sets(input: &str) -> (u32, u32) {
+    let rucksacks = RucksackReorganization::parse(input.as_bytes()).unwrap();
+    (
+        RucksackReorganization::part1(&rucksacks),
+        RucksackReorganization::part2(&rucksacks),
+    )
+}
+
+fn compare_item_sets(c: &mut Criterion) {
+    let input = generators::rucksacks(&mut Random::new(2022), 100_000);
+    assert_eq!(solve_with_hash_sets(&input), solve_with_item_sets(&input));
+    let mut group = c.benchmark_group("item_sets");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("hash_set", |b| b.iter(|| solve_with_hash_sets(&input)));
+    group.bench_function("bit_mask", |b| b.iter(|| solve_with_item_sets(&input)));
+    group.finish();
+}
+
+criterion_group!(benches, compare_item_sets);
+criterion_main!(benches);
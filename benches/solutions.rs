@@ -0,0 +1,65 @@
+//! Time the parsing and each part of every day's solution separately
+//!
+//! Every day is measured on its sample input and on a larger generated input. A single day can be
+//! selected with a filter, for example `cargo bench --bench solutions -- 2022/day-12`.
+use advent_of_code::y2022::generators;
+use advent_of_code::{input_file, solutions, Puzzle};
+use criterion::measurement::WallTime;
+use criterion::{criterion_group, criterion_main, BenchmarkGroup, BenchmarkId, Criterion};
+use std::fs;
+use std::path::Path;
+
+/// A larger input for every day, sized so that each part takes at most around half a second
+fn generated_input(year: u16, day: u8) -> Option<String> {
+    let scale = match day {
+        8 => 200,
+        11 => 10,
+        12 => 40,
+        _ => 10_000,
+    };
+    match year {
+        2022 => generators::generate(day, 2022, scale),
+        _ => None,
+    }
+}
+
+fn bench_input(
+    group: &mut BenchmarkGroup<WallTime>,
+    puzzle: &dyn Puzzle,
+    label: &str,
+    input: &str,
+) {
+    group.bench_with_input(BenchmarkId::new("parse", label), input, |b, input| {
+        b.iter(|| puzzle.parse(&mut input.as_bytes()))
+    });
+    let parsed = puzzle
+        .parse(&mut input.as_bytes())
+        .expect("Invalid benchmark input");
+    group.bench_function(BenchmarkId::new("part1", label), |b| {
+        b.iter(|| parsed.part1())
+    });
+    group.bench_function(BenchmarkId::new("part2", label), |b| {
+        b.iter(|| parsed.part2())
+    });
+}
+
+fn solve_each_day(c: &mut Criterion) {
+    let samples = Path::new(env!("CARGO_MANIFEST_DIR")).join("sample");
+    for (year, calendar) in solutions() {
+        for (day, puzzle) in calendar {
+            let mut group = c.benchmark_group(format!("{}/day-{:02}", year, day));
+            let path = samples.join(input_file(year, day));
+            let sample = fs::read_to_string(&path)
+                .unwrap_or_else(|error| panic!("Unable to read {}: {}", path.display(), error));
+            bench_input(&mut group, puzzle, "sample", &sample);
+            if let Some(generated) = generated_input(year, day) {
+                group.sample_size(10);
+                bench_input(&mut group, puzzle, "generated", &generated);
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group!(benches, solve_each_day);
+criterion_main!(benches);
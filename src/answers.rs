@@ -0,0 +1,304 @@
+//! The answers computed by the solutions, the expected answers recorded in `answers.toml`, and
+//! verification of the former against the latter
+use crate::{input_directory, input_file, is_overridden, solutions, try_new_reader, InputError};
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+/// The answer to one part of a puzzle
+///
+/// Answers are serialized without a tag, so an integer becomes a JSON number, text becomes a JSON
+/// string, and a glyph grid becomes an array of strings, one per row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    /// An image made of characters, such as the letters drawn on a display, one string per row
+    Glyphs(Vec<String>),
+}
+
+impl Display for Answer {
+    /// Render the answer as it would be submitted. Each row of a glyph grid is terminated by a line
+    /// break.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Glyphs(rows) => {
+                for row in rows {
+                    writeln!(f, "{}", row)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    /// An integer answer, or its decimal representation if it does not fit in an `i64`
+    fn from(value: u64) -> Self {
+        i64::try_from(value)
+            .map(Answer::Integer)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+
+impl From<usize> for Answer {
+    /// An integer answer, or its decimal representation if it does not fit in an `i64`
+    fn from(value: usize) -> Self {
+        i64::try_from(value)
+            .map(Answer::Integer)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// The recorded answers to a single day's puzzle. A part without an answer is not verified.
+#[derive(Deserialize, Default)]
+pub struct RecordedAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Recorded answers keyed by input directory, then by year, and then by day
+pub type AnswerKey = BTreeMap<String, BTreeMap<u16, BTreeMap<u8, RecordedAnswers>>>;
+
+/// Read the answers recorded in `answers.toml`
+pub fn load_answers() -> Result<AnswerKey, InputError> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml");
+    let string = fs::read_to_string(&path)
+        .map_err(|error| InputError::UnreadableAnswers(path.clone(), error.to_string()))?;
+    toml::from_str(&string).map_err(|error| InputError::UnreadableAnswers(path, error.to_string()))
+}
+
+/// A computed answer that differs from the recorded one
+pub struct Discrepancy {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Discrepancy {
+    /// Render a line-by-line diff, prefixing expected lines with `-` and computed lines with `+`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} Day {} Part {}:", self.year, self.day, self.part)?;
+        let mut expected = self.expected.lines();
+        let mut actual = self.actual.lines();
+        loop {
+            match (expected.next(), actual.next()) {
+                (None, None) => return Ok(()),
+                (Some(x), Some(y)) if x == y => writeln!(f, "  {}", x)?,
+                (x, y) => {
+                    if let Some(x) = x {
+                        writeln!(f, "- {}", x)?;
+                    }
+                    if let Some(y) = y {
+                        writeln!(f, "+ {}", y)?;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A day whose answers could not be checked because its input could not be read or parsed
+pub struct UnreadableInput {
+    pub year: u16,
+    pub day: u8,
+    pub error: InputError,
+}
+
+impl Display for UnreadableInput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} Day {}: {}", self.year, self.day, self.error)
+    }
+}
+
+/// The outcome of checking the solutions against the recorded answers
+pub struct Verification {
+    /// The number of answers that were compared
+    pub checked: usize,
+    pub discrepancies: Vec<Discrepancy>,
+    /// Days that were not checked because their input could not be read or parsed
+    pub unreadable: Vec<UnreadableInput>,
+    /// Input files that were not checked because `config.toml` replaces them with files from
+    /// outside the input directory, whose answers are not recorded
+    pub skipped: Vec<String>,
+}
+
+/// Solve every puzzle that has a recorded answer for the configured input directory and compare
+/// the results with the recorded answers. Inputs overridden in the `files` table of `config.toml`
+/// are skipped, as the answers recorded for the directory do not apply to them. A day whose input
+/// cannot be read or parsed is recorded as unreadable and the remaining days are still checked.
+pub fn verify() -> Result<Verification, InputError> {
+    let mut answers = load_answers()?;
+    let recorded = answers.remove(&input_directory()?).unwrap_or_default();
+    let solutions = solutions();
+    let mut checked = 0;
+    let mut discrepancies = vec![];
+    let mut skipped = vec![];
+    let mut unreadable = vec![];
+    for (year, recorded) in recorded {
+        for (day, recorded) in recorded {
+            let puzzle = match solutions.get(&year).and_then(|calendar| calendar.get(&day)) {
+                Some(puzzle) => puzzle,
+                None => continue,
+            };
+            let file = input_file(year, day);
+            if is_overridden(&file)? {
+                skipped.push(file);
+                continue;
+            }
+            let parsed = match try_new_reader(&file) {
+                Ok((path, mut reader)) => puzzle
+                    .parse(&mut reader)
+                    .map_err(|error| InputError::Unparseable(path, error)),
+                Err(error) => Err(error),
+            };
+            let parsed = match parsed {
+                Ok(parsed) => parsed,
+                Err(error) => {
+                    unreadable.push(UnreadableInput { year, day, error });
+                    continue;
+                }
+            };
+            let parts = [(1, recorded.part1), (2, recorded.part2)];
+            for (part, expected) in parts {
+                let expected = match expected {
+                    Some(expected) => expected,
+                    None => continue,
+                };
+                let actual = match part {
+                    1 => parsed.part1(),
+                    _ => parsed.part2(),
+                }
+                .to_string();
+                checked += 1;
+                if expected.trim_end() != actual.trim_end() {
+                    discrepancies.push(Discrepancy {
+                        year,
+                        day,
+                        part,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+    Ok(Verification {
+        checked,
+        discrepancies,
+        unreadable,
+        skipped,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{verify, Answer, Discrepancy};
+
+    #[test]
+    fn recorded_answers_match() {
+        let verification = verify().unwrap();
+
+        for discrepancy in &verification.discrepancies {
+            eprintln!("{}", discrepancy);
+        }
+        assert!(verification.discrepancies.is_empty());
+        assert!(verification.unreadable.is_empty());
+    }
+
+    #[test]
+    fn discrepancy_shows_diff() {
+        let discrepancy = Discrepancy {
+            year: 2022,
+            day: 10,
+            part: 2,
+            expected: "#..\n.#.\n".to_string(),
+            actual: "#..\n..#\n".to_string(),
+        };
+
+        assert_eq!(
+            discrepancy.to_string(),
+            "2022 Day 10 Part 2:\n  #..\n- .#.\n+ ..#\n"
+        );
+    }
+
+    #[test]
+    fn answers_serialize_to_json() {
+        let answers = vec![
+            Answer::from(24_000u32),
+            Answer::from("CMZ"),
+            Answer::Glyphs(vec!["#..".to_string(), ".#.".to_string()]),
+        ];
+
+        let json = serde_json::to_string(&answers).unwrap();
+
+        assert_eq!(json, r##"[24000,"CMZ",["#..",".#."]]"##);
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn answers_display_as_submitted() {
+        assert_eq!(Answer::from(-3i32).to_string(), "-3");
+        assert_eq!(Answer::from("MCD").to_string(), "MCD");
+        assert_eq!(
+            Answer::Glyphs(vec!["#.".to_string(), ".#".to_string()]).to_string(),
+            "#.\n.#\n"
+        );
+    }
+
+    #[test]
+    fn integers_of_different_widths_are_equal() {
+        assert_eq!(Answer::from(7u16), Answer::from(7usize));
+    }
+
+    #[test]
+    fn answers_of_different_kinds_are_unequal() {
+        assert_ne!(Answer::from(7u16), Answer::from("7"));
+    }
+
+    #[test]
+    fn oversized_integers_are_kept_as_text() {
+        let answer = Answer::from(u64::MAX);
+
+        assert_eq!(answer, Answer::Text("18446744073709551615".to_string()));
+        assert_eq!(answer.to_string(), u64::MAX.to_string());
+    }
+}
@@ -0,0 +1,319 @@
+//! Command-line runner for the Advent of Code solutions
+//!
+//! ```text
+//! aoc run [--year <year>] --day <day> [--part <part>] [--input <path>] [--json]
+//! aoc run [--year <year>] --all [--parallel] [--json]
+//! aoc verify
+//! aoc calories [--top <count>]
+//! ```
+//!
+//! The year defaults to the most recent one with solutions.
+//!
+//! Each answer is printed on its own line as `Day <day> Part <part>: <answer>`. Answers that span
+//! multiple lines, such as rendered images, begin on the line following the label. With `--json`,
+//! a single report of every answer along with the time spent parsing and solving is printed
+//! instead.
+//!
+//! `--parallel` solves the days, and both parts of each day, concurrently. The answers are still
+//! printed in order, followed by the time taken for each day.
+//!
+//! `verify` compares the answers for the configured input directory with those recorded in
+//! `answers.toml` and exits with a non-zero status if any of them differ or any of the inputs cannot
+//! be read. Inputs replaced in the `files` table of `config.toml` are skipped and listed.
+//!
+//! `calories` reads day 1 style inventories from standard input as they arrive, for example from a
+//! live log, and prints the running total of the top elves (three by default) whenever an elf's
+//! inventory is complete. Malformed lines are reported on standard error and skipped.
+use advent_of_code::answers::{verify, Answer};
+use advent_of_code::report::{DayReport, RunReport};
+use advent_of_code::y2022::day01::TopElves;
+use advent_of_code::{input_file, solutions, try_read_input, Calendar};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Debug;
+use std::fs;
+use std::io::{stdin, BufRead};
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Instant;
+
+const USAGE: &str = "Usage:
+    aoc run [--year <year>] --day <day> [--part <part>] [--input <path>] [--json]
+    aoc run [--year <year>] --all [--parallel] [--json]
+    aoc verify
+    aoc calories [--top <count>]";
+
+/// The action requested on the command line
+enum Command {
+    Run(Request),
+    Verify,
+    /// Keep a running total of the calories carried by the given number of elves
+    Calories(usize),
+}
+
+impl Command {
+    fn parse(arguments: &[String], solutions: &BTreeMap<u16, Calendar>) -> Result<Self, String> {
+        match arguments.first().map(String::as_str) {
+            Some("run") => Ok(Self::Run(Request::parse(&arguments[1..], solutions)?)),
+            Some("verify") if arguments.len() == 1 => Ok(Self::Verify),
+            Some("verify") => Err("verify does not accept any options".to_string()),
+            Some("calories") => match &arguments[1..] {
+                [] => Ok(Self::Calories(3)),
+                [option, count] if option == "--top" => match count.parse::<usize>() {
+                    Ok(count) if count > 0 => Ok(Self::Calories(count)),
+                    _ => Err(format!("--top must be a positive number: {}", count)),
+                },
+                _ => Err("calories only accepts --top <count>".to_string()),
+            },
+            Some(other) => Err(format!("Unknown command: {}", other)),
+            None => Err("No command specified".to_string()),
+        }
+    }
+}
+
+/// The puzzles requested on the command line
+struct Request {
+    year: u16,
+    days: Vec<u8>,
+    parts: Vec<u8>,
+    /// An input file to use instead of the configured one
+    input: Option<PathBuf>,
+    /// Print a machine-readable report instead of one line per answer
+    json: bool,
+    /// Solve the days and parts concurrently
+    parallel: bool,
+}
+
+impl Request {
+    fn parse(arguments: &[String], solutions: &BTreeMap<u16, Calendar>) -> Result<Self, String> {
+        let available_years = solutions.keys().copied().collect::<Vec<u16>>();
+        let mut arguments = arguments.iter();
+        let mut all = false;
+        let mut year = None;
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut json = false;
+        let mut parallel = false;
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--all" => all = true,
+                "--json" => json = true,
+                "--parallel" => parallel = true,
+                "--year" => {
+                    year = Some(parse_number("--year", arguments.next(), &available_years)?)
+                }
+                "--day" => day = Some(arguments.next().ok_or("--day requires a value")?),
+                "--part" => part = Some(parse_number("--part", arguments.next(), &[1, 2])?),
+                "--input" => {
+                    let path = arguments.next().ok_or("--input requires a path")?;
+                    input = Some(PathBuf::from(path));
+                }
+                other => return Err(format!("Unknown option: {}", other)),
+            }
+        }
+        let year = match year.or_else(|| available_years.last().copied()) {
+            Some(year) => year,
+            None => return Err("No solutions available".to_string()),
+        };
+        let available_days = solutions[&year].keys().copied().collect::<Vec<u8>>();
+        let day = match day {
+            Some(day) => Some(parse_number("--day", Some(day), &available_days)?),
+            None => None,
+        };
+        let days = match (all, day) {
+            (true, None) if input.is_none() => available_days.to_vec(),
+            (true, None) => return Err("--input cannot be combined with --all".to_string()),
+            (true, Some(_)) => return Err("--day cannot be combined with --all".to_string()),
+            (false, Some(day)) => vec![day],
+            (false, None) => return Err("Either --day or --all is required".to_string()),
+        };
+        let parts = match part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        };
+        Ok(Self {
+            year,
+            days,
+            parts,
+            input,
+            json,
+            parallel,
+        })
+    }
+}
+
+/// Parse the value of a numeric command-line option that must be one of the `allowed` values
+fn parse_number<N: std::str::FromStr + PartialEq + Debug>(
+    option: &str,
+    value: Option<&String>,
+    allowed: &[N],
+) -> Result<N, String> {
+    let value = value.ok_or_else(|| format!("{} requires a value", option))?;
+    match value.parse::<N>() {
+        Ok(number) if allowed.contains(&number) => Ok(number),
+        _ => Err(format!(
+            "{} must be one of {:?}: {}",
+            option, allowed, value
+        )),
+    }
+}
+
+/// Read the entire puzzle input for a day
+fn read_input(year: u16, day: u8, input: &Option<PathBuf>) -> Result<String, String> {
+    match input {
+        Some(path) => fs::read_to_string(path)
+            .map_err(|error| format!("Unable to read {}: {}", path.display(), error)),
+        None => try_read_input(&input_file(year, day)).map_err(|error| error.to_string()),
+    }
+}
+
+fn print_answer(day: u8, part: u8, answer: &Answer) {
+    match answer {
+        Answer::Glyphs(rows) => println!("Day {} Part {}:\n{}", day, part, rows.join("\n")),
+        _ => println!("Day {} Part {}: {}", day, part, answer),
+    }
+}
+
+fn solve_day(calendar: &Calendar, request: &Request, day: u8) -> Result<DayReport, String> {
+    let input = read_input(request.year, day, &request.input)?;
+    let solve = if request.parallel {
+        DayReport::solve_concurrently
+    } else {
+        DayReport::solve
+    };
+    solve(
+        request.year,
+        day,
+        calendar[&day],
+        &mut input.as_bytes(),
+        &request.parts,
+    )
+    .map_err(|error| error.to_string())
+}
+
+fn print_timings(report: &RunReport) {
+    println!();
+    for day in &report.days {
+        let parts = day
+            .parts
+            .iter()
+            .map(|part| format!("part {} {:?}", part.part, part.solve_time))
+            .collect::<Vec<String>>();
+        println!(
+            "Day {} took {:?} (parse {:?}, {})",
+            day.day,
+            day.wall_time,
+            day.parse_time,
+            parts.join(", ")
+        );
+    }
+    println!(
+        "Finished in {:?} ({:?} in total across all days)",
+        report.wall_time,
+        report.parse_time + report.solve_time
+    );
+}
+
+fn run(solutions: &BTreeMap<u16, Calendar>, request: &Request) {
+    let calendar = &solutions[&request.year];
+    let start = Instant::now();
+    let reports = if request.parallel {
+        request
+            .days
+            .par_iter()
+            .map(|day| solve_day(calendar, request, *day))
+            .collect::<Result<Vec<DayReport>, String>>()
+    } else {
+        request
+            .days
+            .iter()
+            .map(|day| solve_day(calendar, request, *day))
+            .collect::<Result<Vec<DayReport>, String>>()
+    };
+    let reports = reports.unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        exit(1);
+    });
+    let report = RunReport::new(reports, start.elapsed());
+    if request.json {
+        println!(
+            "{}",
+            serde_json::to_string(&report).expect("Unable to serialize report")
+        );
+        return;
+    }
+    for day in &report.days {
+        for part in &day.parts {
+            print_answer(day.day, part.part, &part.answer);
+        }
+    }
+    if request.parallel {
+        print_timings(&report);
+    }
+}
+
+fn run_verification() {
+    let verification = verify().unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        exit(1);
+    });
+    for discrepancy in &verification.discrepancies {
+        println!("{}", discrepancy);
+    }
+    for unreadable in &verification.unreadable {
+        println!("{}", unreadable);
+    }
+    for file in &verification.skipped {
+        println!("Skipped {}, which is overridden in config.toml", file);
+    }
+    println!(
+        "{} of {} answers match",
+        verification.checked - verification.discrepancies.len(),
+        verification.checked
+    );
+    if !verification.discrepancies.is_empty() || !verification.unreadable.is_empty() {
+        exit(1);
+    }
+}
+
+fn run_calories(count: usize) {
+    let mut top_elves = TopElves::new(count);
+    let print_total = |top_elves: &TopElves| {
+        println!(
+            "Elves counted: {}, calories carried by the top {}: {}",
+            top_elves.elves_seen(),
+            count,
+            top_elves.total_calories()
+        )
+    };
+    for line in stdin().lock().split(b'\n') {
+        let line = line.unwrap_or_else(|error| {
+            eprintln!("Unable to read standard input: {}", error);
+            exit(1);
+        });
+        match top_elves.push_bytes(&line) {
+            Ok(true) => print_total(&top_elves),
+            Ok(false) => {}
+            Err(malformed_line) => eprintln!("{}", malformed_line),
+        }
+    }
+    if top_elves.finish_elf() {
+        print_total(&top_elves);
+    }
+}
+
+fn main() {
+    let solutions = solutions();
+    let arguments = env::args().skip(1).collect::<Vec<String>>();
+    let command = Command::parse(&arguments, &solutions).unwrap_or_else(|message| {
+        eprintln!("{}\n\n{}", message, USAGE);
+        exit(2);
+    });
+    match command {
+        Command::Run(request) => run(&solutions, &request),
+        Command::Verify => run_verification(),
+        Command::Calories(count) => run_calories(count),
+    }
+}
@@ -1,161 +1,711 @@
 extern crate core;
 extern crate lazy_static;
 
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-
-use crate::BufReadResult::{BufferingError, EndOfBlock, EndOfInput, PartialBlock};
+pub mod answers;
+pub mod random;
+pub mod report;
+pub mod y2022;
+
+use answers::Answer;
 use serde_derive::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 use std::fs;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::io::{BufRead, BufReader, ErrorKind};
+use std::path::{Path, PathBuf};
+
+/// The solution to a single day's puzzle
+pub trait Solution {
+    /// The puzzle input once it has been parsed. It must be shareable between threads so that both
+    /// parts can be solved concurrently.
+    type Input: Send + Sync;
+    /// The answer to the first part of the puzzle
+    type Part1: Into<Answer>;
+    /// The answer to the second part of the puzzle
+    type Part2: Into<Answer>;
+    /// The reason the puzzle input could not be parsed. Puzzles that accept any input use
+    /// `Infallible`.
+    type Error: Error + Send + Sync + 'static;
+
+    /// Parse the puzzle input from any source of text
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// A `Solution` whose input and answer types have been erased so that every day can be handled
+/// uniformly
+pub trait Puzzle: Sync {
+    /// Parse the puzzle input in preparation for solving either part
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Box<dyn ParsedPuzzle>, ParseError>;
+}
+
+/// The reason a puzzle input could not be parsed, whatever the puzzle
+pub type ParseError = Box<dyn Error + Send + Sync>;
+
+/// A puzzle whose input has been parsed and is ready to be solved
+pub trait ParsedPuzzle: Send + Sync {
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+}
+
+impl<S: Solution + Sync + 'static> Puzzle for S {
+    fn parse(&self, reader: &mut dyn BufRead) -> Result<Box<dyn ParsedPuzzle>, ParseError> {
+        let input = S::parse(reader)?;
+        Ok(Box::new(ParsedInput::<S>(input)))
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> ParsedPuzzle for ParsedInput<S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0).into()
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0).into()
+    }
+}
+
+/// The solutions for a single year keyed by day number
+pub type Calendar = BTreeMap<u8, &'static dyn Puzzle>;
+
+/// Every available solution keyed by year
+pub fn solutions() -> BTreeMap<u16, Calendar> {
+    let mut result = BTreeMap::new();
+    result.insert(2022, y2022::solutions());
+    result
+}
 
-#[derive(Deserialize)]
+/// The name of the input file for a given day, relative to the input directory. Each year's inputs
+/// are kept in their own sub-directory, for example `2022/day-07.txt`.
+pub fn input_file(year: u16, day: u8) -> String {
+    format!("{}/day-{:02}.txt", year, day)
+}
+
+/// The environment variable that names the directory containing the problem input files
+pub const INPUT_DIRECTORY_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// The contents of the optional `config.toml` file in the crate root.
+///
+/// Each problem input file is located by checking, in order:
+/// 1. the directory named by the `AOC_INPUT_DIR` environment variable
+/// 2. the path specified for that file in the `files` table
+/// 3. the `input_directory`
+/// 4. the `sample` directory
+///
+/// Unrecognised keys are rejected so that a misspelt setting does not silently fall back to the
+/// sample inputs.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
 struct Config {
     /// The directory that contains the problem input files
     /// It should be relative to the directory specified by the `CARGO_MANIFEST_DIR` environment
     /// variable.
     input_directory: Option<String>,
+    /// Specific input files to use in place of those in the input directory, keyed by the name of
+    /// the file they replace, for example `"2022/day-07.txt" = "/home/elf/terminal.txt"`
+    #[serde(default)]
+    files: BTreeMap<String, String>,
 }
 
-fn new_reader(file: &str) -> BufReader<File> {
-    let manifest_dir = env!("CARGO_MANIFEST_DIR");
-    let input_directory =
-        match fs::read_to_string(Path::new(&format!("{}/config.toml", manifest_dir))) {
-            Ok(string) => {
-                let result: Result<Config, toml::de::Error> = toml::from_str(&string);
-                match result {
-                    Ok(config) => match config.input_directory {
-                        Some(input_directory) => input_directory,
-                        None => String::from("sample"),
-                    },
-                    Err(_) => String::from("sample"),
-                }
+impl Config {
+    /// Read `config.toml` if it exists
+    fn load() -> Result<Self, InputError> {
+        let config_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("config.toml");
+        match fs::read_to_string(&config_path) {
+            Ok(string) => toml::from_str(&string)
+                .map_err(|error| InputError::UnreadableConfig(config_path, error.to_string())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(InputError::UnreadableConfig(config_path, error.to_string())),
+        }
+    }
+
+    /// Determine the directory containing the problem input files
+    ///
+    /// Parameters:
+    /// - `environment_directory` - the value of the `AOC_INPUT_DIR` environment variable, if set
+    fn input_directory(&self, environment_directory: Option<String>) -> String {
+        environment_directory
+            .filter(|directory| !directory.is_empty())
+            .or_else(|| self.input_directory.clone())
+            .unwrap_or_else(|| String::from("sample"))
+    }
+
+    /// Determine the location of a problem input file
+    ///
+    /// Parameters:
+    /// - `environment_directory` - the value of the `AOC_INPUT_DIR` environment variable, if set
+    /// - `file` - the name of the input file
+    fn input_path(&self, environment_directory: Option<String>, file: &str) -> PathBuf {
+        let environment_directory = environment_directory.filter(|directory| !directory.is_empty());
+        if environment_directory.is_none() {
+            if let Some(path) = self.files.get(file) {
+                return PathBuf::from(path);
+            }
+        }
+        Path::new(&self.input_directory(environment_directory)).join(file)
+    }
+
+    /// Whether a problem input file is read from somewhere other than the input directory because
+    /// of an entry in the `files` table
+    fn is_overridden(&self, environment_directory: Option<String>, file: &str) -> bool {
+        let directory = Path::new(&self.input_directory(environment_directory.clone())).join(file);
+        self.input_path(environment_directory, file) != directory
+    }
+}
+
+/// A problem encountered while locating or reading a problem input file
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist
+    MissingFile(PathBuf),
+    /// The configuration file exists but could not be read or parsed
+    UnreadableConfig(PathBuf, String),
+    /// The file of recorded answers could not be read or parsed
+    UnreadableAnswers(PathBuf, String),
+    /// The input file contains bytes that are not valid UTF-8
+    InvalidUtf8(PathBuf),
+    /// The input file could not be opened or read
+    Io(PathBuf, std::io::Error),
+    /// The input file was read but is not a valid input for its puzzle
+    Unparseable(PathBuf, ParseError),
+}
+
+impl InputError {
+    fn from_io(path: &Path, error: std::io::Error) -> Self {
+        match error.kind() {
+            ErrorKind::NotFound => Self::MissingFile(path.to_path_buf()),
+            ErrorKind::InvalidData => Self::InvalidUtf8(path.to_path_buf()),
+            _ => Self::Io(path.to_path_buf(), error),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingFile(path) => write!(f, "Input file not found: {}", path.display()),
+            Self::UnreadableConfig(path, reason) => {
+                write!(f, "Unable to read {}: {}", path.display(), reason)
             }
-            Err(_) => String::from("sample"),
-        };
-    let file =
-        File::open(Path::new(&format!("{}/{}", input_directory, file))).expect("file not found"); // FIXME should let the client decide whether or not to panic
-    BufReader::new(file)
+            Self::UnreadableAnswers(path, reason) => write!(
+                f,
+                "Unable to read the recorded answers in {}: {}",
+                path.display(),
+                reason
+            ),
+            Self::InvalidUtf8(path) => write!(f, "Input is not valid UTF-8: {}", path.display()),
+            Self::Io(path, error) => write!(f, "Unable to read {}: {}", path.display(), error),
+            Self::Unparseable(path, error) => {
+                write!(f, "Unable to parse {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(_, error) => Some(error),
+            Self::Unparseable(_, error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Determine the directory containing the problem input files. See `Config` for the lookup order.
+fn input_directory() -> Result<String, InputError> {
+    Ok(Config::load()?.input_directory(env::var(INPUT_DIRECTORY_VARIABLE).ok()))
+}
+
+/// Whether a problem input file is replaced by an entry in the `files` table of `config.toml`
+fn is_overridden(file: &str) -> Result<bool, InputError> {
+    Ok(Config::load()?.is_overridden(env::var(INPUT_DIRECTORY_VARIABLE).ok(), file))
+}
+
+/// Resolve the location of a problem input file according to the environment and configuration.
+/// See `Config` for the lookup order.
+pub fn input_path(file: &str) -> Result<PathBuf, InputError> {
+    Ok(Config::load()?.input_path(env::var(INPUT_DIRECTORY_VARIABLE).ok(), file))
+}
+
+/// Resolve the location of a problem input file and open it for reading
+fn try_new_reader(file: &str) -> Result<(PathBuf, BufReader<File>), InputError> {
+    let path = input_path(file)?;
+    let file = File::open(&path).map_err(|error| InputError::from_io(&path, error))?;
+    Ok((path, BufReader::new(file)))
+}
+
+fn new_reader(file: &str) -> BufReader<File> {
+    match try_new_reader(file) {
+        Ok((_, reader)) => reader,
+        Err(error) => panic!("{}", error),
+    }
 }
 
 pub fn get_lines(file: &str) -> impl Iterator<Item = String> {
-    let reader = new_reader(file);
+    lines(new_reader(file))
+}
+
+/// Read any source of text line by line, for example a string, standard input, or a file.
+pub fn lines<R: BufRead>(reader: R) -> impl Iterator<Item = String> {
     reader.lines().map(Result::unwrap)
 }
 
-/// A wrapper for a BufRead that splits around empty lines.
+/// Read an entire problem input file into memory without panicking, for example to split it with
+/// `block_slices`.
+pub fn try_read_input(file: &str) -> Result<String, InputError> {
+    let path = input_path(file)?;
+    fs::read_to_string(&path).map_err(|error| InputError::from_io(&path, error))
+}
+
+/// Read a problem input file line by line without panicking.
 ///
-/// This allows one to iterate through blocks of text without needing to read the whole input into
-/// memory at once. The specific delimiter it looks for is "\n\n". No other delimiters are supported.
-struct Blocks<R: BufRead> {
-    reader: R,
+/// Returns an error if the file cannot be located or opened. Each line read may individually fail,
+/// for example if the file is truncated or is not valid UTF-8.
+pub fn try_get_lines(
+    file: &str,
+) -> Result<impl Iterator<Item = Result<String, InputError>>, InputError> {
+    let (path, reader) = try_new_reader(file)?;
+    Ok(reader
+        .lines()
+        .map(move |line| line.map_err(|error| InputError::from_io(&path, error))))
 }
 
-enum BufReadResult<'a, E> {
-    /// There are no more bytes to be read.
-    EndOfInput,
-    /// Part of a block is available, it may be the beginning of a block or a middle portion.
-    PartialBlock(&'a [u8]),
-    /// The provided array includes the end of the block. It may also be an entire block.
-    EndOfBlock(&'a [u8]),
-    /// An error occurred while reading from the underlying buffer.
-    BufferingError(E),
+/// A wrapper for a BufRead that splits around separator lines.
+///
+/// This allows one to iterate through blocks of text without needing to read the whole input into
+/// memory at once. Lines may end with either "\n" or "\r\n". A line is a separator if the
+/// predicate returns `true` for its contents, excluding the line ending. Consecutive separators do
+/// not produce empty blocks and separators are not included in the blocks.
+struct Blocks<R: BufRead, P: FnMut(&str) -> bool> {
+    reader: R,
+    is_separator: P,
 }
 
-impl<R: BufRead> Blocks<R> {
-    /// Read a portion of the buffer.
-    ///
-    /// Read some number of bytes from the underlying buffer. This may need to be called multiple
-    /// times in order to read a full block of text; blocks are delimited by empty lines.
-    ///
-    /// **Important:** This method does not consume bytes read from the underlying reader. Callers
-    /// **must** consume the appropriate number of bytes.
+impl<R: BufRead, P: FnMut(&str) -> bool> Blocks<R, P> {
+    /// Read the next block of text as raw bytes. Each line in the block, including the last, is
+    /// terminated by "\n" regardless of the line ending used in the input.
     ///
-    /// * `previous_byte` - the last byte read from a previous `try_read` invocation. This is needed
-    ///                     because the delimiter is two bytes ("\n\n") and therefore may span
-    ///                     two calls to `try_read`. If a previous byte is not available, provide
-    ///                     any value other than '\n'.
-    fn try_read(&mut self, previous_byte: u8) -> BufReadResult<std::io::Error> {
-        return match self.reader.fill_buf() {
-            Ok(buffer) => {
-                if buffer.is_empty() {
-                    return EndOfInput;
-                }
-                let mut previous = previous_byte;
-                for i in 0..buffer.len() {
-                    let current = buffer[i];
-                    if previous == b'\n' && current == b'\n' {
-                        return EndOfBlock(&buffer[0..i + 1]);
+    /// Returns `None` once the input has been exhausted or an error if the underlying reader fails.
+    fn next_block(&mut self) -> Option<std::io::Result<Vec<u8>>> {
+        // Each line is read directly onto the end of the block and removed again if it turns out
+        // to be a separator, so the block is accumulated in a single growing buffer.
+        let mut block = vec![];
+        loop {
+            let line_start = block.len();
+            match self.reader.read_until(b'\n', &mut block) {
+                Ok(0) if block.is_empty() => return None,
+                Ok(0) => return Some(Ok(block)),
+                Ok(_) => {
+                    let contents_length = strip_line_ending(&block[line_start..]).len();
+                    let contents = &block[line_start..line_start + contents_length];
+                    let is_separator = match std::str::from_utf8(contents) {
+                        Ok(contents) => (self.is_separator)(contents),
+                        Err(_) => false,
+                    };
+                    if is_separator {
+                        block.truncate(line_start);
+                        if !block.is_empty() {
+                            return Some(Ok(block));
+                        }
+                    } else {
+                        block.truncate(line_start + contents_length);
+                        block.push(b'\n');
                     }
-                    previous = current;
                 }
-                PartialBlock(buffer)
+                Err(error) => return Some(Err(error)),
             }
-            Err(error) => BufferingError(error),
-        };
+        }
     }
 }
 
-impl<R: BufRead> Iterator for Blocks<R> {
-    type Item = String;
+impl<R: BufRead, P: FnMut(&str) -> bool> Iterator for Blocks<R, P> {
+    /// A block of text, or the reason it could not be read. A block that is not valid UTF-8 is
+    /// reported as an error of kind `InvalidData`, just like `BufRead::lines`.
+    type Item = std::io::Result<String>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut bytes = vec![];
+        Some(self.next_block()?.and_then(|bytes| {
+            String::from_utf8(bytes)
+                .map_err(|error| std::io::Error::new(ErrorKind::InvalidData, error.utf8_error()))
+        }))
+    }
+}
 
-        loop {
-            let previous_byte = if !bytes.is_empty() {
-                bytes[bytes.len() - 1]
-            } else {
-                b'_'
+/// An iterator over blocks of text separated by separator lines that borrows from a string which
+/// has already been loaded into memory, such as one returned by `try_read_input`.
+///
+/// Unlike `Blocks`, this does not copy any text. As a result, line endings are preserved as they
+/// appear in the input and each block includes the line ending of its last line. Use
+/// `str::lines` to iterate through the lines of a block regardless of the line ending used.
+struct BlockSlices<'a, P: FnMut(&str) -> bool> {
+    input: &'a str,
+    /// The index of the first byte of the input that has not been examined yet
+    position: usize,
+    is_separator: P,
+}
+
+impl<'a, P: FnMut(&str) -> bool> Iterator for BlockSlices<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut start = None;
+        while self.position < self.input.len() {
+            let remaining = &self.input[self.position..];
+            let line_length = match remaining.find('\n') {
+                Some(index) => index + 1,
+                None => remaining.len(),
             };
-            let mut bytes_read = 0_usize;
-            let mut complete = false;
-            let mut result = None;
-
-            match &self.try_read(previous_byte) {
-                EndOfInput => {
-                    if !bytes.is_empty() {
-                        result = Some(String::from_utf8_lossy(&bytes).to_string());
-                    }
-                    complete = true;
-                }
-                PartialBlock(partial) => {
-                    bytes = [&bytes, *partial].concat();
-                    bytes_read = partial.len();
-                }
-                EndOfBlock(partial) => {
-                    bytes = [&bytes, *partial].concat();
-                    result = Some(String::from_utf8_lossy(&bytes).to_string());
-                    bytes_read = partial.len();
-                    complete = true;
-                }
-                BufferingError(error) => {
-                    eprintln!("Error buffering blocks: {}", error);
-                    complete = true;
+            let line_start = self.position;
+            self.position += line_length;
+            let contents = strip_line_ending(&remaining.as_bytes()[..line_length]).len();
+            if (self.is_separator)(&remaining[..contents]) {
+                if let Some(start) = start {
+                    return Some(&self.input[start..line_start]);
                 }
-            }
-            self.reader.consume(bytes_read);
-            if complete {
-                return result;
+            } else if start.is_none() {
+                start = Some(line_start);
             }
         }
+        start.map(|start| &self.input[start..])
+    }
+}
+
+/// Split text that has already been loaded into memory into blocks separated by blank lines,
+/// without copying it.
+pub fn block_slices(input: &str) -> impl Iterator<Item = &str> {
+    block_slices_separated_by(input, is_blank_line)
+}
+
+/// Split text that has already been loaded into memory into blocks separated by lines that satisfy
+/// a predicate, without copying it. The predicate receives the contents of each line without its
+/// line ending.
+pub fn block_slices_separated_by<P>(input: &str, is_separator: P) -> impl Iterator<Item = &str>
+where
+    P: FnMut(&str) -> bool,
+{
+    BlockSlices {
+        input,
+        position: 0,
+        is_separator,
     }
 }
 
+/// Remove the trailing "\n" or "\r\n" from a line, if present
+fn strip_line_ending(line: &[u8]) -> &[u8] {
+    let line = line.strip_suffix(b"\n").unwrap_or(line);
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+/// The default block separator, a line that is empty or contains only whitespace
+pub fn is_blank_line(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
 pub fn get_block_strings(file: &str) -> impl Iterator<Item = String> {
-    let reader = new_reader(file);
-    Blocks { reader }
+    blocks(new_reader(file))
+}
+
+/// Read any source of text in blocks separated by blank lines, for example a string, standard
+/// input, or a file.
+///
+/// Panics if the text cannot be read or is not valid UTF-8. See `try_blocks` for a variant that
+/// reports these errors.
+pub fn blocks<R: BufRead>(reader: R) -> impl Iterator<Item = String> {
+    try_blocks(reader).map(Result::unwrap)
+}
+
+/// Read any source of text in blocks separated by lines that satisfy a predicate. The predicate
+/// receives the contents of each line without its line ending.
+///
+/// Panics if the text cannot be read or is not valid UTF-8. See `try_blocks_separated_by` for a
+/// variant that reports these errors.
+pub fn blocks_separated_by<R, P>(reader: R, is_separator: P) -> impl Iterator<Item = String>
+where
+    R: BufRead,
+    P: FnMut(&str) -> bool,
+{
+    try_blocks_separated_by(reader, is_separator).map(Result::unwrap)
+}
+
+/// Read any source of text in blocks separated by blank lines, reporting any block that cannot be
+/// read. This allows callers to distinguish the end of the input from a failed read.
+pub fn try_blocks<R: BufRead>(reader: R) -> impl Iterator<Item = std::io::Result<String>> {
+    try_blocks_separated_by(reader, is_blank_line)
+}
+
+/// Read any source of text in blocks separated by lines that satisfy a predicate, reporting any
+/// block that cannot be read.
+pub fn try_blocks_separated_by<R, P>(
+    reader: R,
+    is_separator: P,
+) -> impl Iterator<Item = std::io::Result<String>>
+where
+    R: BufRead,
+    P: FnMut(&str) -> bool,
+{
+    Blocks {
+        reader,
+        is_separator,
+    }
+}
+
+/// Read a problem input file in blocks of text separated by blank lines, without panicking.
+///
+/// Returns an error if the file cannot be located or opened. Each block read may individually fail,
+/// for example if the file is truncated or is not valid UTF-8.
+pub fn try_get_block_strings(
+    file: &str,
+) -> Result<impl Iterator<Item = Result<String, InputError>>, InputError> {
+    let (path, reader) = try_new_reader(file)?;
+    Ok(try_blocks(reader)
+        .map(move |block| block.map_err(|error| InputError::from_io(&path, error))))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        block_slices, block_slices_separated_by, blocks, blocks_separated_by, input_file,
+        new_reader, solutions, try_blocks, try_get_block_strings, try_get_lines, try_read_input,
+        Config, InputError,
+    };
+    use std::io::{BufReader, ErrorKind, Read};
+    use std::path::PathBuf;
+
+    #[test]
+    fn missing_file_is_reported() {
+        let result = try_get_lines("no-such-day.txt");
+
+        assert!(matches!(result, Err(InputError::MissingFile(_))));
+    }
+
+    #[test]
+    fn fallible_lines_match_lines() {
+        let lines = try_get_lines("2022/day-04.txt")
+            .unwrap()
+            .collect::<Result<Vec<String>, InputError>>()
+            .unwrap();
+
+        assert_eq!(
+            lines,
+            crate::get_lines("2022/day-04.txt").collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn fallible_blocks_match_blocks() {
+        let blocks = try_get_block_strings("2022/day-13.txt")
+            .unwrap()
+            .collect::<Result<Vec<String>, InputError>>()
+            .unwrap();
+
+        assert_eq!(
+            blocks,
+            crate::get_block_strings("2022/day-13.txt").collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn blocks_from_string() {
+        let input = "1\n2\n\n3\n";
+
+        let result = blocks(input.as_bytes()).collect::<Vec<String>>();
+
+        assert_eq!(result, vec!["1\n2\n".to_string(), "3\n".to_string()]);
+    }
+
+    #[test]
+    fn blocks_with_windows_line_endings() {
+        let input = "1\r\n2\r\n\r\n3";
+
+        let result = blocks(input.as_bytes()).collect::<Vec<String>>();
+
+        assert_eq!(result, vec!["1\n2\n".to_string(), "3\n".to_string()]);
+    }
+
+    #[test]
+    fn blocks_separated_by_whitespace_only_lines() {
+        let input = "1\n \t\n\n\n2\n  \r\n3\n";
+
+        let result = blocks(input.as_bytes()).collect::<Vec<String>>();
+
+        assert_eq!(
+            result,
+            vec!["1\n".to_string(), "2\n".to_string(), "3\n".to_string()]
+        );
+    }
+
+    #[test]
+    fn blocks_with_custom_separator() {
+        let input = "a\n\nb\n---\nc\n";
+
+        let result =
+            blocks_separated_by(input.as_bytes(), |line| line == "---").collect::<Vec<String>>();
+
+        assert_eq!(result, vec!["a\n\nb\n".to_string(), "c\n".to_string()]);
+    }
+
+    #[test]
+    fn separators_spanning_buffer_boundaries() {
+        let input = "10\r\n20\r\n \r\n30\n\n40\r\n\r\n";
+        let expected = vec![
+            "10\n20\n".to_string(),
+            "30\n".to_string(),
+            "40\n".to_string(),
+        ];
+
+        for capacity in 1..=input.len() {
+            let reader = BufReader::with_capacity(capacity, input.as_bytes());
+
+            let result = blocks(reader).collect::<Vec<String>>();
+
+            assert_eq!(result, expected, "Buffer capacity: {}", capacity);
+        }
+    }
+
+    #[test]
+    fn every_day_is_registered() {
+        for (year, calendar) in solutions() {
+            for (day, puzzle) in calendar {
+                let parsed = puzzle
+                    .parse(&mut new_reader(&input_file(year, day)))
+                    .unwrap();
+
+                assert!(
+                    !parsed.part1().to_string().is_empty(),
+                    "{} day {} part 1",
+                    year,
+                    day
+                );
+                assert!(
+                    !parsed.part2().to_string().is_empty(),
+                    "{} day {} part 2",
+                    year,
+                    day
+                );
+            }
+        }
+    }
+
+    /// A reader that fails after producing some of its contents
+    struct TruncatedReader<'a> {
+        contents: &'a [u8],
+    }
+
+    impl Read for TruncatedReader<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> std::io::Result<usize> {
+            if self.contents.is_empty() {
+                return Err(std::io::Error::new(ErrorKind::UnexpectedEof, "truncated"));
+            }
+            self.contents.read(buffer)
+        }
+    }
+
+    #[test]
+    fn block_reading_errors_are_reported() {
+        let reader = BufReader::new(TruncatedReader {
+            contents: b"1\n2\n\n3\n",
+        });
+
+        let mut result = try_blocks(reader);
+
+        assert_eq!(result.next().unwrap().unwrap(), "1\n2\n");
+        assert_eq!(
+            result.next().unwrap().unwrap_err().kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_blocks_are_reported() {
+        let input: &[u8] = b"1\n\n\xff\xfe\n\n3\n";
+
+        let result = try_blocks(input).collect::<Vec<std::io::Result<String>>>();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].as_ref().unwrap(), "1\n");
+        assert_eq!(
+            result[1].as_ref().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+        assert_eq!(result[2].as_ref().unwrap(), "3\n");
+    }
+
+    #[test]
+    fn block_slices_borrow_from_input() {
+        let input = "1\r\n2\r\n  \r\n\r\n3\n\n4";
+
+        let result = block_slices(input).collect::<Vec<&str>>();
+
+        assert_eq!(result, vec!["1\r\n2\r\n", "3\n", "4"]);
+    }
+
+    #[test]
+    fn block_slices_with_custom_separator() {
+        let input = "a\n\nb\n---\nc\n";
+
+        let result = block_slices_separated_by(input, |line| line == "---").collect::<Vec<&str>>();
+
+        assert_eq!(result, vec!["a\n\nb\n", "c\n"]);
+    }
+
+    #[test]
+    fn block_slices_match_blocks() {
+        let input = try_read_input("2022/day-11.txt").unwrap();
+
+        let slices = block_slices(&input)
+            .map(|block| block.lines().map(|line| format!("{}\n", line)).collect())
+            .collect::<Vec<String>>();
+
+        assert_eq!(slices, blocks(input.as_bytes()).collect::<Vec<String>>());
+    }
+
+    #[test]
+    fn config_lookup_order() {
+        let config: Config = toml::from_str(
+            r#"
+            input_directory = "personal"
+
+            [files]
+            "2022/day-07.txt" = "elsewhere/terminal.txt"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.input_path(None, "2022/day-07.txt"),
+            PathBuf::from("elsewhere/terminal.txt")
+        );
+        assert_eq!(
+            config.input_path(None, "2022/day-08.txt"),
+            PathBuf::from("personal/2022/day-08.txt")
+        );
+        assert_eq!(
+            config.input_path(Some("team".to_string()), "2022/day-07.txt"),
+            PathBuf::from("team/2022/day-07.txt")
+        );
+        assert_eq!(
+            Config::default().input_path(None, "2022/day-07.txt"),
+            PathBuf::from("sample/2022/day-07.txt")
+        );
+        assert!(config.is_overridden(None, "2022/day-07.txt"));
+        assert!(!config.is_overridden(None, "2022/day-08.txt"));
+        assert!(!config.is_overridden(Some("team".to_string()), "2022/day-07.txt"));
+    }
+
+    #[test]
+    fn misspelt_config_is_rejected() {
+        let result = toml::from_str::<Config>(r#"input_dir = "personal""#);
+
+        assert!(result.is_err());
+    }
 }
@@ -0,0 +1,75 @@
+//! A small, seedable source of pseudo-random numbers for generating puzzle inputs
+//!
+//! This is not suitable for anything that needs unpredictability. Its only purpose is to produce
+//! the same sequence for the same seed on every platform, so that generated inputs are
+//! reproducible.
+use std::ops::RangeInclusive;
+
+/// A SplitMix64 pseudo-random number generator
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// The next 64 pseudo-random bits
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A pseudo-random number in `0..bound`. Panics if `bound` is zero.
+    pub fn below(&mut self, bound: usize) -> usize {
+        assert!(bound > 0, "The bound must be positive");
+        (self.next_u64() % bound as u64) as usize
+    }
+
+    /// A pseudo-random number within an inclusive range
+    pub fn between(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    /// Pick an element uniformly at random. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// Shuffle the items in place
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::random::Random;
+
+    #[test]
+    fn same_seed_same_sequence() {
+        let mut x = Random::new(2022);
+        let mut y = Random::new(2022);
+
+        for _ in 0..1_000 {
+            assert_eq!(x.next_u64(), y.next_u64());
+        }
+    }
+
+    #[test]
+    fn values_stay_in_range() {
+        let mut random = Random::new(7);
+
+        for _ in 0..1_000 {
+            assert!(random.below(3) < 3);
+            assert!((-5..=5).contains(&random.between(-5..=5)));
+        }
+    }
+}
@@ -0,0 +1,230 @@
+//! Machine-readable reports of the answers to each puzzle and the time taken to compute them
+//!
+//! Reports serialize to JSON with durations expressed as whole nanoseconds, for example:
+//!
+//! ```text
+//! {"days":[{"year":2022,"day":1,"parse_time_ns":5120,
+//!   "parts":[{"part":1,"answer":24000,"solve_time_ns":410}],"wall_time_ns":5561}],
+//!  "parse_time_ns":5120,"solve_time_ns":410,"wall_time_ns":6012}
+//! ```
+use crate::answers::Answer;
+use crate::{ParseError, ParsedPuzzle, Puzzle};
+use rayon::prelude::*;
+use serde::Serializer;
+use serde_derive::Serialize;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a puzzle
+#[derive(Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    /// The time taken to compute the answer from the parsed input
+    #[serde(rename = "solve_time_ns", serialize_with = "nanoseconds")]
+    pub solve_time: Duration,
+}
+
+impl PartReport {
+    fn solve(parsed: &dyn ParsedPuzzle, part: u8) -> Result<Self, SolveError> {
+        let start = Instant::now();
+        let answer = match part {
+            1 => parsed.part1(),
+            2 => parsed.part2(),
+            _ => return Err(SolveError::NoSuchPart(part)),
+        };
+        Ok(Self {
+            part,
+            answer,
+            solve_time: start.elapsed(),
+        })
+    }
+}
+
+/// The reason a day's puzzle could not be solved
+#[derive(Debug)]
+pub enum SolveError {
+    /// A part other than part 1 or part 2 was requested
+    NoSuchPart(u8),
+    /// The input is not valid for the puzzle
+    InvalidInput(ParseError),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSuchPart(part) => write!(f, "There is no part {}, only parts 1 and 2", part),
+            Self::InvalidInput(error) => write!(f, "Invalid input: {}", error),
+        }
+    }
+}
+
+/// The answers to the requested parts of one day's puzzle
+#[derive(Serialize)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    /// The time taken to parse the input, which is shared by both parts
+    #[serde(rename = "parse_time_ns", serialize_with = "nanoseconds")]
+    pub parse_time: Duration,
+    pub parts: Vec<PartReport>,
+    /// The elapsed time for parsing and solving. When the parts are solved concurrently this is
+    /// less than the sum of the parse and solve times.
+    #[serde(rename = "wall_time_ns", serialize_with = "nanoseconds")]
+    pub wall_time: Duration,
+}
+
+impl DayReport {
+    /// Parse the input and solve the requested parts one after the other, timing each step
+    pub fn solve(
+        year: u16,
+        day: u8,
+        puzzle: &dyn Puzzle,
+        input: &mut dyn BufRead,
+        parts: &[u8],
+    ) -> Result<Self, SolveError> {
+        let start = Instant::now();
+        let parsed = puzzle.parse(input).map_err(SolveError::InvalidInput)?;
+        let parse_time = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|part| PartReport::solve(parsed.as_ref(), *part))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            year,
+            day,
+            parse_time,
+            parts,
+            wall_time: start.elapsed(),
+        })
+    }
+
+    /// Parse the input and then solve the requested parts concurrently on the rayon thread pool.
+    /// The parts are reported in the order requested.
+    pub fn solve_concurrently(
+        year: u16,
+        day: u8,
+        puzzle: &dyn Puzzle,
+        input: &mut dyn BufRead,
+        parts: &[u8],
+    ) -> Result<Self, SolveError> {
+        let start = Instant::now();
+        let parsed = puzzle.parse(input).map_err(SolveError::InvalidInput)?;
+        let parse_time = start.elapsed();
+        let parts = parts
+            .par_iter()
+            .map(|part| PartReport::solve(parsed.as_ref(), *part))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            year,
+            day,
+            parse_time,
+            parts,
+            wall_time: start.elapsed(),
+        })
+    }
+
+    /// The time taken to solve every requested part
+    pub fn solve_time(&self) -> Duration {
+        self.parts.iter().map(|part| part.solve_time).sum()
+    }
+}
+
+/// The reports for every day in a run along with the total time spent in each phase
+#[derive(Serialize)]
+pub struct RunReport {
+    pub days: Vec<DayReport>,
+    #[serde(rename = "parse_time_ns", serialize_with = "nanoseconds")]
+    pub parse_time: Duration,
+    #[serde(rename = "solve_time_ns", serialize_with = "nanoseconds")]
+    pub solve_time: Duration,
+    /// The elapsed time for the whole run. When days are solved concurrently this is less than the
+    /// sum of the parse and solve times.
+    #[serde(rename = "wall_time_ns", serialize_with = "nanoseconds")]
+    pub wall_time: Duration,
+}
+
+impl RunReport {
+    pub fn new(days: Vec<DayReport>, wall_time: Duration) -> Self {
+        let parse_time = days.iter().map(|day| day.parse_time).sum();
+        let solve_time = days.iter().map(DayReport::solve_time).sum();
+        Self {
+            days,
+            parse_time,
+            solve_time,
+            wall_time,
+        }
+    }
+}
+
+fn nanoseconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::{DayReport, RunReport, SolveError};
+    use crate::y2022::day01::CalorieCounting;
+    use crate::{input_file, new_reader};
+    use serde_json::Value;
+    use std::time::Duration;
+
+    #[test]
+    fn day_report_contains_answers() {
+        let mut reader = new_reader(&input_file(2022, 1));
+
+        let report = DayReport::solve(2022, 1, &CalorieCounting, &mut reader, &[1, 2]).unwrap();
+
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.parts[0].answer.to_string(), "24000");
+        assert_eq!(report.parts[1].answer.to_string(), "45000");
+    }
+
+    #[test]
+    fn concurrent_parts_are_reported_in_order() {
+        let mut reader = new_reader(&input_file(2022, 1));
+
+        let report =
+            DayReport::solve_concurrently(2022, 1, &CalorieCounting, &mut reader, &[2, 1]).unwrap();
+
+        let parts = report
+            .parts
+            .iter()
+            .map(|part| (part.part, part.answer.to_string()))
+            .collect::<Vec<(u8, String)>>();
+        assert_eq!(
+            parts,
+            vec![(2, "45000".to_string()), (1, "24000".to_string())]
+        );
+    }
+
+    #[test]
+    fn only_parts_one_and_two_are_solved() {
+        let mut reader = new_reader(&input_file(2022, 1));
+
+        let result = DayReport::solve_concurrently(2022, 1, &CalorieCounting, &mut reader, &[1, 3]);
+
+        assert!(matches!(result, Err(SolveError::NoSuchPart(3))));
+    }
+
+    #[test]
+    fn run_report_serializes_to_json() {
+        let mut report =
+            DayReport::solve(2022, 1, &CalorieCounting, &mut "1\n\n2\n".as_bytes(), &[2]).unwrap();
+        report.parse_time = Duration::from_nanos(5);
+        report.parts[0].solve_time = Duration::from_nanos(7);
+        report.wall_time = Duration::from_nanos(12);
+
+        let report = RunReport::new(vec![report], Duration::from_nanos(13));
+        let json = serde_json::to_value(report).unwrap();
+
+        let expected: Value = serde_json::from_str(
+            r#"{"days":[{"year":2022,"day":1,"parse_time_ns":5,
+                "parts":[{"part":2,"answer":3,"solve_time_ns":7}],"wall_time_ns":12}],
+                "parse_time_ns":5,"solve_time_ns":7,"wall_time_ns":13}"#,
+        )
+        .unwrap();
+        assert_eq!(json, expected);
+    }
+}
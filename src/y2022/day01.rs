@@ -0,0 +1,818 @@
+/// --- Day 1: Calorie Counting ---
+/// https://adventofcode.com/2022/day/1
+use crate::{is_blank_line, new_reader, Solution};
+use std::borrow::Borrow;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+
+pub type CalorieCount = u32;
+
+/// The calories carried by one or more elves. This is wider than [CalorieCount] so that totals of
+/// arbitrarily long inventories cannot overflow.
+pub type CalorieTotal = u64;
+
+pub fn get_elves(max: usize) -> Result<Vec<Elf>, MalformedLine> {
+    Ok(top_elves(
+        parse(new_reader("2022/day-01.txt")).collect::<Result<Vec<Elf>, MalformedLine>>()?,
+        max,
+    ))
+}
+
+/// Parse the calorie counts of each elf's food items, one block per elf. A line that is neither
+/// blank nor a calorie count is reported as an error in place of the elf it belongs to.
+///
+/// Panics if the text cannot be read.
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Elf, MalformedLine>> {
+    let mut lines = reader.split(b'\n');
+    let mut inventories = Inventories::default();
+    std::iter::from_fn(move || {
+        for line in lines.by_ref() {
+            let line = line.expect("Unable to read calorie counts");
+            match inventories.push_bytes(&line) {
+                Ok(Some(elf)) => return Some(Ok(elf)),
+                Ok(None) => {}
+                Err(malformed_line) => return Some(Err(malformed_line)),
+            }
+        }
+        inventories.finish_elf().map(Ok)
+    })
+}
+
+/// Identify the elves carrying the most calories from any source of text, such as standard input.
+/// Malformed lines are skipped and passed to `on_malformed_line` instead of causing a panic, so
+/// that the caller decides how many of them to keep.
+pub fn read_top_elves<R, F>(
+    reader: R,
+    max: usize,
+    mut on_malformed_line: F,
+) -> std::io::Result<Vec<Elf>>
+where
+    R: BufRead,
+    F: FnMut(MalformedLine),
+{
+    let mut top_elves = TopElves::new(max);
+    for line in reader.split(b'\n') {
+        if let Err(malformed_line) = top_elves.push_bytes(&line?) {
+            on_malformed_line(malformed_line);
+        }
+    }
+    top_elves.finish_elf();
+    Ok(top_elves.top())
+}
+
+/// A line that is neither blank nor a calorie count
+#[derive(Debug, PartialEq, Eq)]
+pub struct MalformedLine {
+    /// The position of the line in the input, starting from one
+    pub line_number: usize,
+    /// The line without its line ending. Bytes that are not valid UTF-8 are replaced.
+    pub contents: String,
+}
+
+impl Display for MalformedLine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {}: invalid calorie count: {:?}",
+            self.line_number, self.contents
+        )
+    }
+}
+
+impl Error for MalformedLine {}
+
+/// Assembles elves from input that arrives one line at a time. Only the items of the current elf
+/// are kept in memory.
+#[derive(Default)]
+struct Inventories {
+    /// The items of the elf whose inventory is being read, if any
+    current: Option<Vec<CalorieCount>>,
+    elves_seen: usize,
+    lines_seen: usize,
+}
+
+impl Inventories {
+    /// Process the next line of input, without its line ending. Returns the elf whose inventory the
+    /// line completed, if any.
+    fn push_line(&mut self, line: &str) -> Result<Option<Elf>, MalformedLine> {
+        self.lines_seen += 1;
+        if is_blank_line(line) {
+            return Ok(self.finish_elf());
+        }
+        match line.trim().parse::<CalorieCount>() {
+            Ok(calories) => {
+                self.current.get_or_insert_with(Vec::new).push(calories);
+                Ok(None)
+            }
+            Err(_) => Err(MalformedLine {
+                line_number: self.lines_seen,
+                contents: line.to_string(),
+            }),
+        }
+    }
+
+    /// Process the next line of input as raw bytes, with or without its line ending
+    fn push_bytes(&mut self, line: &[u8]) -> Result<Option<Elf>, MalformedLine> {
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        match std::str::from_utf8(line) {
+            Ok(line) => self.push_line(line),
+            Err(_) => {
+                self.lines_seen += 1;
+                Err(MalformedLine {
+                    line_number: self.lines_seen,
+                    contents: String::from_utf8_lossy(line).into_owned(),
+                })
+            }
+        }
+    }
+
+    /// Complete the inventory of the current elf, if any
+    fn finish_elf(&mut self) -> Option<Elf> {
+        let items = self.current.take()?;
+        let elf = Elf::new(self.elves_seen, items);
+        self.elves_seen += 1;
+        Some(elf)
+    }
+}
+
+/// Identifies the elves carrying the most calories from input that arrives one line at a time, for
+/// example a log on standard input. Only the top elves and the items of the current elf are kept
+/// in memory, so the input may be arbitrarily long.
+pub struct TopElves {
+    inventories: Inventories,
+    /// The top elves so far, according to the ordering of elves
+    top: Least<Elf>,
+}
+
+impl TopElves {
+    pub fn new(max: usize) -> Self {
+        Self {
+            inventories: Inventories::default(),
+            top: Least::new(max),
+        }
+    }
+
+    /// Process the next line of input, without its line ending. Returns `Ok(true)` if the line
+    /// completed an elf's inventory. A malformed line is skipped and reported with its line number.
+    pub fn push_line(&mut self, line: &str) -> Result<bool, MalformedLine> {
+        let elf = self.inventories.push_line(line)?;
+        Ok(self.rank(elf))
+    }
+
+    /// Process the next line of input as raw bytes, with or without its line ending. A line that
+    /// is not valid UTF-8 is reported as malformed.
+    pub fn push_bytes(&mut self, line: &[u8]) -> Result<bool, MalformedLine> {
+        let elf = self.inventories.push_bytes(line)?;
+        Ok(self.rank(elf))
+    }
+
+    /// Complete the inventory of the current elf, for example at the end of the input. Returns
+    /// `false` if there was no elf in progress.
+    pub fn finish_elf(&mut self) -> bool {
+        let elf = self.inventories.finish_elf();
+        self.rank(elf)
+    }
+
+    fn rank(&mut self, elf: Option<Elf>) -> bool {
+        match elf {
+            Some(elf) => {
+                self.top.push(elf);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The number of elves whose inventories are complete
+    pub fn elves_seen(&self) -> usize {
+        self.inventories.elves_seen
+    }
+
+    /// The top elves so far, ordered from most calories to fewest
+    pub fn top(&self) -> Vec<Elf> {
+        self.top.to_sorted_vec()
+    }
+
+    /// The total calories carried by the top elves so far
+    pub fn total_calories(&self) -> CalorieTotal {
+        self.top.iter().map(|elf| elf.calories_carried).sum()
+    }
+}
+
+/// The total calories carried by the elf carrying the most
+pub fn part1(elves: &[Elf]) -> CalorieTotal {
+    top_elves(elves, 1)
+        .iter()
+        .map(|elf| elf.calories_carried)
+        .sum()
+}
+
+/// The total calories carried by the three elves carrying the most
+pub fn part2(elves: &[Elf]) -> CalorieTotal {
+    top_elves(elves, 3)
+        .iter()
+        .map(|elf| elf.calories_carried)
+        .sum()
+}
+
+/// Identify the elves carrying the most calories, ordered from most to fewest and then by position.
+/// The elves may be owned or borrowed.
+pub fn top_elves<E, I>(elves: I, max: usize) -> Vec<E>
+where
+    E: Borrow<Elf>,
+    I: IntoIterator<Item = E>,
+{
+    least_by_key(elves, max, |elf| {
+        (Reverse(elf.borrow().calories_carried), elf.borrow().index)
+    })
+}
+
+/// Identify the elves carrying the fewest calories, ordered from fewest to most and then by
+/// position. The elves may be owned or borrowed.
+pub fn bottom_elves<E, I>(elves: I, max: usize) -> Vec<E>
+where
+    E: Borrow<Elf>,
+    I: IntoIterator<Item = E>,
+{
+    least_by_key(elves, max, |elf| {
+        (elf.borrow().calories_carried, elf.borrow().index)
+    })
+}
+
+/// The elves carrying more than `threshold` calories, in their original order
+pub fn elves_above<'a, I: IntoIterator<Item = &'a Elf>>(
+    elves: I,
+    threshold: CalorieTotal,
+) -> impl Iterator<Item = &'a Elf> {
+    elves
+        .into_iter()
+        .filter(move |elf| elf.calories_carried > threshold)
+}
+
+/// The median number of calories carried by an elf. With an even number of elves, this is the
+/// mean of the two middle loads. Returns `None` if there are no elves.
+pub fn median_load(elves: &[Elf]) -> Option<f64> {
+    let mut loads = elves
+        .iter()
+        .map(|elf| elf.calories_carried)
+        .collect::<Vec<CalorieTotal>>();
+    if loads.is_empty() {
+        return None;
+    }
+    let middle = loads.len() / 2;
+    let (lower, upper, _) = loads.select_nth_unstable(middle);
+    let upper = *upper as f64;
+    if elves.len() % 2 == 1 {
+        return Some(upper);
+    }
+    let lower = *lower.iter().max().expect("No lower half") as f64;
+    Some((lower + upper) / 2.0)
+}
+
+/// Moving a single food item from one elf to another
+#[derive(Debug, PartialEq, Eq)]
+pub struct Transfer {
+    pub calories: CalorieCount,
+    /// The index of the elf giving up the item
+    pub from: usize,
+    /// The index of the elf receiving the item
+    pub to: usize,
+}
+
+/// Find the fewest item transfers, up to `max_transfers`, that leave every elf's load within
+/// `tolerance` calories of every other's. Returns `None` if that takes more than `max_transfers`
+/// transfers or cannot be achieved at all.
+///
+/// No item needs to move more than once, so this searches for the smallest set of items to move
+/// and their destinations, trying one transfer, then two, and so on. With n items and m elves, each
+/// attempt at k transfers examines up to (n·m)^k moves, so keep `max_transfers` small.
+pub fn balancing_transfers(
+    elves: &[Elf],
+    tolerance: CalorieTotal,
+    max_transfers: usize,
+) -> Option<Vec<Transfer>> {
+    // each item with the position of the elf carrying it, largest first so the search can stop as
+    // soon as the remaining items are too small to close the gap
+    let mut items = elves
+        .iter()
+        .enumerate()
+        .flat_map(|(owner, elf)| elf.items.iter().map(move |calories| (owner, *calories)))
+        .collect::<Vec<(usize, CalorieCount)>>();
+    items.sort_by_key(|(_, calories)| Reverse(*calories));
+    let mut loads = elves
+        .iter()
+        .map(|elf| elf.calories_carried)
+        .collect::<Vec<CalorieTotal>>();
+    let mut moves = vec![];
+    (0..=max_transfers.min(items.len()))
+        .find(|budget| find_moves(&items, *budget, tolerance, &mut loads, &mut moves))
+        .map(|_| {
+            moves
+                .iter()
+                .map(|(item, to)| {
+                    let (from, calories) = items[*item];
+                    Transfer {
+                        calories,
+                        from: elves[from].index,
+                        to: elves[*to].index,
+                    }
+                })
+                .collect()
+        })
+}
+
+/// Try to bring the loads within tolerance by moving at most `budget` more items, considering only
+/// those after the last item in `moves`. Each move is the position of the item and the position of
+/// the elf that receives it. On success the moves are left in `moves`.
+fn find_moves(
+    items: &[(usize, CalorieCount)],
+    budget: usize,
+    tolerance: CalorieTotal,
+    loads: &mut [CalorieTotal],
+    moves: &mut Vec<(usize, usize)>,
+) -> bool {
+    let spread = match (loads.iter().max(), loads.iter().min()) {
+        (Some(max), Some(min)) => max - min,
+        _ => 0,
+    };
+    if spread <= tolerance {
+        return true;
+    }
+    let next = moves.last().map_or(0, |(item, _)| item + 1);
+    // moving an item of `x` calories narrows the spread by at most `2x`
+    let largest = items
+        .get(next)
+        .map_or(0, |(_, calories)| CalorieTotal::from(*calories));
+    if spread - tolerance > 2 * largest * budget as CalorieTotal {
+        return false;
+    }
+    for item in next..items.len() {
+        let (from, calories) = items[item];
+        let calories = CalorieTotal::from(calories);
+        for to in (0..loads.len()).filter(|to| *to != from) {
+            loads[from] -= calories;
+            loads[to] += calories;
+            moves.push((item, to));
+            if find_moves(items, budget - 1, tolerance, loads, moves) {
+                return true;
+            }
+            moves.pop();
+            loads[from] += calories;
+            loads[to] -= calories;
+        }
+    }
+    false
+}
+
+/// The `max` least items in ascending order. Only `max + 1` items are held at any time, so this
+/// takes O(n log max) time for n items.
+fn least<T: Ord, I: IntoIterator<Item = T>>(items: I, max: usize) -> Vec<T> {
+    let mut least = Least::new(max);
+    for item in items {
+        least.push(item);
+    }
+    least.heap.into_sorted_vec()
+}
+
+/// The least items seen so far, up to a maximum number of them
+struct Least<T: Ord> {
+    max: usize,
+    heap: BinaryHeap<T>,
+}
+
+impl<T: Ord> Least<T> {
+    fn new(max: usize) -> Self {
+        Self {
+            max,
+            heap: BinaryHeap::with_capacity(max + 1),
+        }
+    }
+
+    fn push(&mut self, item: T) {
+        self.heap.push(item);
+        if self.heap.len() > self.max {
+            // the greatest item is at the top of the heap
+            self.heap.pop();
+        }
+    }
+
+    /// The items in no particular order
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.heap.iter()
+    }
+
+    /// The items in ascending order
+    fn to_sorted_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.heap.clone().into_sorted_vec()
+    }
+}
+
+/// The `max` items with the least keys, in ascending order of key
+fn least_by_key<T, K: Ord, I: IntoIterator<Item = T>, F: Fn(&T) -> K>(
+    items: I,
+    max: usize,
+    key: F,
+) -> Vec<T> {
+    least(items.into_iter().map(|item| Keyed(key(&item), item)), max)
+        .into_iter()
+        .map(|Keyed(_, item)| item)
+        .collect()
+}
+
+/// An item compared only by its key
+struct Keyed<K, T>(K, T);
+
+impl<K: Ord, T> Eq for Keyed<K, T> {}
+
+impl<K: Ord, T> PartialEq<Self> for Keyed<K, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K: Ord, T> PartialOrd<Self> for Keyed<K, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, T> Ord for Keyed<K, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+/// An elf and the food items they carry. Elves are ordered from the most calories carried to the
+/// fewest, and then by their position in the input.
+#[derive(Debug, Clone)]
+pub struct Elf {
+    /// The position of this elf in the input, starting from zero
+    index: usize,
+    /// The calories in each food item, in the order listed
+    items: Vec<CalorieCount>,
+    calories_carried: CalorieTotal,
+}
+
+impl Elf {
+    pub fn new(index: usize, items: Vec<CalorieCount>) -> Self {
+        let calories_carried = items.iter().copied().map(CalorieTotal::from).sum();
+        Self {
+            index,
+            items,
+            calories_carried,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn items(&self) -> &[CalorieCount] {
+        &self.items
+    }
+
+    pub fn calories_carried(&self) -> CalorieTotal {
+        self.calories_carried
+    }
+
+    /// The number of food items carried
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// The calories in the most calorific item, if any
+    pub fn largest_item(&self) -> Option<CalorieCount> {
+        self.items.iter().copied().max()
+    }
+
+    /// The mean calories per item, if any
+    pub fn mean_item(&self) -> Option<f64> {
+        if self.items.is_empty() {
+            return None;
+        }
+        Some(self.calories_carried as f64 / self.items.len() as f64)
+    }
+}
+
+impl Eq for Elf {}
+
+impl PartialEq<Self> for Elf {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl PartialOrd<Self> for Elf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Elf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .calories_carried
+            .cmp(&self.calories_carried)
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+/// Calorie Counting
+pub struct CalorieCounting;
+
+impl Solution for CalorieCounting {
+    type Input = Vec<Elf>;
+    type Part1 = CalorieTotal;
+    type Part2 = CalorieTotal;
+    type Error = MalformedLine;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        parse(reader).collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day01::{
+        balancing_transfers, bottom_elves, elves_above, get_elves, median_load, parse,
+        read_top_elves, top_elves, CalorieCount, CalorieTotal, Elf, MalformedLine, TopElves,
+        Transfer,
+    };
+
+    const INPUT: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    fn elves(input: &str) -> Vec<Elf> {
+        parse(input.as_bytes()).collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn part1() {
+        let result = super::part1(&get_elves(1).unwrap());
+
+        println!("Part 1: {}", result);
+    }
+
+    #[test]
+    fn part2() {
+        let result = super::part2(&get_elves(3).unwrap());
+
+        println!("Part 2: {}", result);
+    }
+
+    #[test]
+    fn parse_from_string() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000\n";
+
+        let elves = top_elves(elves(input), 2);
+
+        let calories = elves
+            .iter()
+            .map(|elf| elf.calories_carried)
+            .collect::<Vec<CalorieTotal>>();
+        assert_eq!(calories, vec![11000, 4000]);
+    }
+
+    #[test]
+    fn top_elves_keep_identity_and_items() {
+        let elves = top_elves(elves(INPUT), 3);
+
+        let summary = elves
+            .iter()
+            .map(|elf| (elf.index(), elf.items().to_vec()))
+            .collect::<Vec<(usize, Vec<CalorieCount>)>>();
+        assert_eq!(
+            summary,
+            vec![
+                (3, vec![7000, 8000, 9000]),
+                (2, vec![5000, 6000]),
+                (4, vec![10000])
+            ]
+        );
+    }
+
+    #[test]
+    fn bottom_elves_are_ordered_from_fewest() {
+        let elves = bottom_elves(elves(INPUT), 2);
+
+        let indices = elves.iter().map(Elf::index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![1, 0]);
+    }
+
+    #[test]
+    fn ties_are_broken_by_position() {
+        let elves = top_elves(elves("3\n\n1\n2\n\n3\n"), 3);
+
+        let indices = elves.iter().map(Elf::index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn bottom_ties_are_broken_by_position() {
+        let elves = bottom_elves(elves("3\n\n1\n2\n\n3\n\n5\n"), 3);
+
+        let indices = elves.iter().map(Elf::index).collect::<Vec<usize>>();
+        assert_eq!(indices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn median_and_threshold_queries() {
+        let elves = elves(INPUT);
+
+        assert_eq!(median_load(&elves), Some(10000.0));
+        assert_eq!(median_load(&elves[..4]), Some(8500.0));
+        assert_eq!(median_load(&[]), None);
+        let above = elves_above(&elves, 10000)
+            .map(Elf::index)
+            .collect::<Vec<usize>>();
+        assert_eq!(above, vec![2, 3]);
+    }
+
+    #[test]
+    fn streaming_matches_parsing() {
+        let mut malformed_lines = vec![];
+        let streamed =
+            read_top_elves(INPUT.as_bytes(), 3, |line| malformed_lines.push(line)).unwrap();
+
+        assert!(malformed_lines.is_empty());
+        assert_eq!(streamed, top_elves(elves(INPUT), 3));
+        let items = streamed
+            .iter()
+            .map(|elf| elf.items().to_vec())
+            .collect::<Vec<Vec<CalorieCount>>>();
+        assert_eq!(
+            items,
+            vec![vec![7000, 8000, 9000], vec![5000, 6000], vec![10000]]
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_reported() {
+        let input: &[u8] = b"100\r\nbanana\r\n200\r\n\r\n\xff\n300\n";
+
+        let mut malformed_lines = vec![];
+        let elves = read_top_elves(input, 1, |line| malformed_lines.push(line)).unwrap();
+
+        assert_eq!(elves[0].calories_carried(), 300);
+        assert_eq!(
+            malformed_lines,
+            vec![
+                MalformedLine {
+                    line_number: 2,
+                    contents: "banana".to_string()
+                },
+                MalformedLine {
+                    line_number: 5,
+                    contents: "\u{FFFD}".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_lines_are_parse_errors() {
+        let result = parse("1\n\n2\nbanana\n".as_bytes()).collect::<Result<Vec<Elf>, _>>();
+
+        assert_eq!(
+            result.err(),
+            Some(MalformedLine {
+                line_number: 4,
+                contents: "banana".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_blocks_are_not_elves() {
+        let mut malformed_lines = vec![];
+        let elves = read_top_elves("1\n\nbanana\n\n2\n".as_bytes(), 5, |line| {
+            malformed_lines.push(line)
+        })
+        .unwrap();
+
+        let summary = elves
+            .iter()
+            .map(|elf| (elf.index(), elf.calories_carried()))
+            .collect::<Vec<(usize, CalorieTotal)>>();
+        assert_eq!(summary, vec![(1, 2), (0, 1)]);
+        assert_eq!(malformed_lines.len(), 1);
+    }
+
+    #[test]
+    fn totals_do_not_overflow() {
+        let input = "4000000000\n4000000000\n\n4000000000\n";
+
+        let mut malformed_lines = vec![];
+        let elves = read_top_elves(input.as_bytes(), 2, |line| malformed_lines.push(line)).unwrap();
+
+        assert!(malformed_lines.is_empty());
+        assert_eq!(elves[0].calories_carried(), 8_000_000_000);
+        let mut top_elves = TopElves::new(2);
+        for line in input.lines() {
+            top_elves.push_line(line).unwrap();
+        }
+        top_elves.finish_elf();
+        assert_eq!(top_elves.total_calories(), 12_000_000_000);
+    }
+
+    #[test]
+    fn running_totals() {
+        let mut top_elves = TopElves::new(2);
+        let mut totals = vec![];
+
+        for line in INPUT.lines() {
+            if top_elves.push_line(line).unwrap() {
+                totals.push(top_elves.total_calories());
+            }
+        }
+        top_elves.finish_elf();
+        totals.push(top_elves.total_calories());
+
+        assert_eq!(totals, vec![6000, 10000, 17000, 35000, 35000]);
+        assert_eq!(top_elves.elves_seen(), 5);
+    }
+
+    #[test]
+    fn item_statistics() {
+        let elves = elves(INPUT);
+
+        assert_eq!(elves[3].item_count(), 3);
+        assert_eq!(elves[3].largest_item(), Some(9000));
+        assert_eq!(elves[3].mean_item(), Some(8000.0));
+        assert_eq!(Elf::new(0, vec![]).largest_item(), None);
+        assert_eq!(Elf::new(0, vec![]).mean_item(), None);
+    }
+
+    #[test]
+    fn transfers_balance_loads() {
+        let elves = vec![Elf::new(0, vec![6, 4]), Elf::new(1, vec![2])];
+
+        assert_eq!(
+            balancing_transfers(&elves, 2, 5),
+            Some(vec![Transfer {
+                calories: 4,
+                from: 0,
+                to: 1
+            }])
+        );
+        assert_eq!(balancing_transfers(&elves, 8, 5), Some(vec![]));
+    }
+
+    #[test]
+    fn transfers_are_minimal() {
+        // Moving the 4 from the heaviest to the lightest elf cannot help, but moving a 1 can
+        let elves = vec![
+            Elf::new(0, vec![4]),
+            Elf::new(1, vec![1, 1]),
+            Elf::new(2, vec![]),
+        ];
+
+        assert_eq!(
+            balancing_transfers(&elves, 3, 5),
+            Some(vec![Transfer {
+                calories: 1,
+                from: 1,
+                to: 2
+            }])
+        );
+        let elves = vec![Elf::new(0, vec![5, 4, 3, 3, 1]), Elf::new(1, vec![])];
+        assert_eq!(
+            balancing_transfers(&elves, 0, 5).map(|moves| moves.len()),
+            Some(2)
+        );
+        assert_eq!(balancing_transfers(&[], 0, 5), Some(vec![]));
+    }
+
+    #[test]
+    fn transfers_are_limited() {
+        let elves = vec![Elf::new(0, vec![5, 4, 3, 3, 1]), Elf::new(1, vec![])];
+
+        assert_eq!(balancing_transfers(&elves, 0, 1), None);
+        assert_eq!(
+            balancing_transfers(&elves, 0, 2).map(|moves| moves.len()),
+            Some(2)
+        );
+        assert_eq!(balancing_transfers(&elves, 16, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn indivisible_items_cannot_always_be_balanced() {
+        let elves = vec![Elf::new(0, vec![10]), Elf::new(1, vec![])];
+
+        assert_eq!(balancing_transfers(&elves, 1, 5), None);
+    }
+}
@@ -0,0 +1,195 @@
+use crate::y2022::day02::parse::{decipher_codes, load_lines};
+use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+use crate::y2022::day02::{Day2ParseError, Game, GameRound, ResponseStrategy};
+use std::collections::HashMap;
+use std::io::BufRead;
+
+/// The codes used in a strategy guide for each shape and outcome
+pub struct Cipher {
+    opponent_codes: HashMap<String, usize>,
+    response_codes: HashMap<String, usize>,
+    /// The opponent's code for each shape, by position in the game
+    opponent_letters: Vec<String>,
+    /// The response code for each shape, by position in the game
+    response_letters: Vec<String>,
+    strategy_codes: HashMap<String, ResponseStrategy>,
+}
+
+impl Cipher {
+    /// A cipher in which each letter stands for the shape at the same position in the game, or for
+    /// losing, drawing and winning respectively. The response and strategy letters may overlap, as
+    /// they do in the puzzle, or be distinct.
+    pub fn from_letters(
+        game: &Game,
+        opponent_letters: &str,
+        response_letters: &str,
+        strategy_letters: &str,
+    ) -> Result<Self, &'static str> {
+        let shape_codes = |letters: &str| {
+            let codes = letters
+                .chars()
+                .enumerate()
+                .map(|(shape, letter)| (letter.to_string(), shape))
+                .collect::<HashMap<String, usize>>();
+            if letters.chars().count() != game.shape_count() || codes.len() != game.shape_count() {
+                Err("There must be exactly one distinct letter per shape")
+            } else {
+                Ok((codes, letters.chars().map(String::from).collect()))
+            }
+        };
+        let strategy_codes = strategy_letters
+            .chars()
+            .zip([Lose, Draw, Win])
+            .map(|(letter, strategy)| (letter.to_string(), strategy))
+            .collect::<HashMap<String, ResponseStrategy>>();
+        if strategy_letters.chars().count() != 3 || strategy_codes.len() != 3 {
+            return Err("There must be exactly one distinct letter per outcome");
+        }
+        let (opponent_codes, opponent_letters) = shape_codes(opponent_letters)?;
+        let (response_codes, response_letters) = shape_codes(response_letters)?;
+        Ok(Self {
+            opponent_codes,
+            response_codes,
+            opponent_letters,
+            response_letters,
+            strategy_codes,
+        })
+    }
+
+    /// The codes from the puzzle: A, B and C for the opponent and X, Y and Z for the second column
+    pub fn classic() -> Self {
+        Self::from_letters(&Game::rock_paper_scissors(), "ABC", "XYZ", "XYZ")
+            .expect("Invalid cipher")
+    }
+
+    /// The codes for a round in which the second column is the shape to play, or `None` if the
+    /// cipher has no code for one of the shapes
+    pub fn encipher(&self, opponent: usize, response: usize) -> Option<(&str, &str)> {
+        Some((
+            self.opponent_letters.get(opponent)?.as_str(),
+            self.response_letters.get(response)?.as_str(),
+        ))
+    }
+
+    /// The shape an opponent's code stands for
+    pub fn opponent_shape(&self, code: &str) -> Option<usize> {
+        self.opponent_codes.get(code).copied()
+    }
+
+    /// The shape a code in the second column stands for, if it is a response code
+    pub fn response_shape(&self, code: &str) -> Option<usize> {
+        self.response_codes.get(code).copied()
+    }
+
+    /// The outcome a code in the second column stands for, if it is a strategy code
+    pub fn strategy(&self, code: &str) -> Option<ResponseStrategy> {
+        self.strategy_codes.get(code).copied()
+    }
+
+    /// Decipher a single line of a strategy guide. The second column must be a response code, a
+    /// strategy code or both. Errors are reported as being on line 1.
+    pub fn decipher(&self, line: &str) -> Result<GameRound, Day2ParseError> {
+        self.decipher_line(line, 1)
+    }
+
+    pub(super) fn decipher_line(
+        &self,
+        line: &str,
+        line_number: usize,
+    ) -> Result<GameRound, Day2ParseError> {
+        let (opponent, (response, strategy)) = decipher_codes(
+            line,
+            line_number,
+            |code| self.opponent_shape(code),
+            |code| {
+                let response = self.response_shape(code);
+                let strategy = self.strategy(code);
+                if response.is_none() && strategy.is_none() {
+                    None
+                } else {
+                    Some((response, strategy))
+                }
+            },
+        )?;
+        Ok(GameRound {
+            opponent,
+            response,
+            strategy,
+        })
+    }
+
+    /// Parse an entire strategy guide, reporting every line that cannot be read or parsed rather
+    /// than stopping at the first
+    pub fn load<R: BufRead>(&self, reader: R) -> Result<Vec<GameRound>, Vec<Day2ParseError>> {
+        load_lines(reader, |line, line_number| {
+            self.decipher_line(line, line_number)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::tests::round;
+    use crate::y2022::day02::ResponseStrategy::Win;
+    use crate::y2022::day02::{Cipher, Day2ParseError, Expected, Game};
+    use proptest::prelude::*;
+
+    #[test]
+    fn configurable_cipher() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let cipher = Cipher::from_letters(&game, "rpsSl", "RPSKL", "-=+").unwrap();
+
+        let shape_round = cipher.decipher("S K").unwrap();
+        let strategy_round = cipher.decipher("l +").unwrap();
+
+        assert_eq!(game.shape_name(shape_round.opponent), "Spock");
+        assert_eq!(shape_round.response, game.shape_named("Spock"));
+        assert_eq!(shape_round.strategy, None);
+        assert_eq!(game.shape_name(strategy_round.opponent), "Lizard");
+        assert_eq!(strategy_round.response, None);
+        assert_eq!(strategy_round.strategy, Some(Win));
+        assert_eq!(game.naïve_total(&[shape_round]), Some(7));
+        assert_eq!(game.total(&[shape_round]), None);
+        assert!(cipher.decipher("S ?").is_err());
+        assert_eq!(
+            cipher.decipher("S K +"),
+            Err(Day2ParseError::Token {
+                line_number: 1,
+                column: 5,
+                token: Some("+".to_string()),
+                expected: Expected::EndOfLine,
+            })
+        );
+        assert_eq!(
+            cipher
+                .load("S K\nr x\n".as_bytes())
+                .unwrap_err()
+                .iter()
+                .map(Day2ParseError::line_number)
+                .collect::<Vec<usize>>(),
+            vec![2]
+        );
+        assert!(Cipher::from_letters(&game, "ABC", "XYZ", "XYZ").is_err());
+    }
+
+    #[test]
+    fn classic_rules_agree_with_puzzle() {
+        let guide = "A Y\nB X\nC Z\n";
+        let rounds = Cipher::classic().load(guide.as_bytes()).unwrap();
+        let game = Game::rock_paper_scissors();
+
+        assert_eq!(game.naïve_total(&rounds), Some(15));
+        assert_eq!(game.total(&rounds), Some(12));
+    }
+
+    proptest! {
+        #[test]
+        fn engine_scores_every_round_like_puzzle(round in round()) {
+            let game = Game::rock_paper_scissors();
+            let game_round = Cipher::classic().decipher(&round.to_string()).unwrap();
+
+            prop_assert_eq!(game.naïve_total(&[game_round]), Some(round.naïve_score() as u32));
+            prop_assert_eq!(game.total(&[game_round]), Some(round.score() as u32));
+        }
+    }
+}
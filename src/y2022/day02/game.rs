@@ -0,0 +1,225 @@
+use crate::y2022::day02::ResponseStrategy;
+use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+use std::cmp::Reverse;
+
+/// The rules of a cyclic game such as Rock Paper Scissors or Rock Paper Scissors Lizard Spock.
+///
+/// Shapes are identified by their position in the list the game was created with. Every shape
+/// beats the shapes an odd number of places before it in that list (wrapping around) and is beaten
+/// by those an even number of places before it, so a game with an odd number of shapes is
+/// balanced: each shape beats exactly half of the others.
+pub struct Game {
+    shape_names: Vec<String>,
+    shape_scores: Vec<u16>,
+    loss_score: u16,
+    draw_score: u16,
+    win_score: u16,
+}
+
+impl Game {
+    /// A game with the given shapes, each scored by its position starting from 1, and the same
+    /// outcome scores as the strategy guide: 0 for a loss, 3 for a draw and 6 for a win
+    pub fn new(shape_names: &[&str]) -> Result<Self, &'static str> {
+        if shape_names.len() < 3 || shape_names.len() % 2 == 0 {
+            return Err("A cyclic game needs an odd number of shapes, at least three");
+        }
+        let mut unique = shape_names.to_vec();
+        unique.sort_unstable();
+        unique.dedup();
+        if unique.len() != shape_names.len() {
+            return Err("Shape names must be unique");
+        }
+        Ok(Self {
+            shape_names: shape_names.iter().map(|name| name.to_string()).collect(),
+            shape_scores: (1..=shape_names.len() as u16).collect(),
+            loss_score: 0,
+            draw_score: 3,
+            win_score: 6,
+        })
+    }
+
+    /// The game from the puzzle
+    pub fn rock_paper_scissors() -> Self {
+        Self::new(&["Rock", "Paper", "Scissors"]).expect("Invalid game")
+    }
+
+    /// The five-shape variant, in which Spock smashes scissors and vaporises rock, and lizard
+    /// eats paper and poisons Spock
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::new(&["Rock", "Paper", "Scissors", "Spock", "Lizard"]).expect("Invalid game")
+    }
+
+    /// Replace the score for playing each shape
+    pub fn with_shape_scores(mut self, shape_scores: &[u16]) -> Result<Self, &'static str> {
+        if shape_scores.len() != self.shape_count() {
+            return Err("There must be exactly one score per shape");
+        }
+        self.shape_scores = shape_scores.to_vec();
+        Ok(self)
+    }
+
+    /// Replace the score for each outcome of a round
+    pub fn with_outcome_scores(mut self, loss: u16, draw: u16, win: u16) -> Self {
+        self.loss_score = loss;
+        self.draw_score = draw;
+        self.win_score = win;
+        self
+    }
+
+    pub fn shape_count(&self) -> usize {
+        self.shape_names.len()
+    }
+
+    pub fn shape_name(&self, shape: usize) -> &str {
+        &self.shape_names[shape]
+    }
+
+    /// Look up a shape by its name
+    pub fn shape_named(&self, name: &str) -> Option<usize> {
+        self.shape_names
+            .iter()
+            .position(|candidate| candidate == name)
+    }
+
+    /// The result of a round from the point of view of the player
+    pub fn outcome(&self, opponent: usize, player: usize) -> ResponseStrategy {
+        let distance = (player + self.shape_count() - opponent) % self.shape_count();
+        if distance == 0 {
+            Draw
+        } else if distance % 2 == 1 {
+            Win
+        } else {
+            Lose
+        }
+    }
+
+    /// The player's score for a single round
+    pub fn score(&self, opponent: usize, player: usize) -> u16 {
+        self.outcome_score(self.outcome(opponent, player)) + self.shape_score(player)
+    }
+
+    /// The score for playing a shape, regardless of the outcome
+    pub fn shape_score(&self, shape: usize) -> u16 {
+        self.shape_scores[shape]
+    }
+
+    /// The score for an outcome, regardless of the shape played
+    pub fn outcome_score(&self, outcome: ResponseStrategy) -> u16 {
+        match outcome {
+            Lose => self.loss_score,
+            Draw => self.draw_score,
+            Win => self.win_score,
+        }
+    }
+
+    /// The shape that achieves the desired outcome against the opponent. When several shapes do,
+    /// the one scoring the most is chosen, preferring the earliest shape on a tie.
+    pub fn respond_to(&self, opponent: usize, strategy: ResponseStrategy) -> usize {
+        (0..self.shape_count())
+            .filter(|player| self.outcome(opponent, *player) == strategy)
+            .min_by_key(|player| (Reverse(self.shape_scores[*player]), *player))
+            .expect("Every outcome is possible in a cyclic game")
+    }
+
+    /// The total score if the second column is the shape to play, or `None` if any of the rounds
+    /// has no such interpretation
+    pub fn naïve_total(&self, rounds: &[GameRound]) -> Option<u32> {
+        rounds
+            .iter()
+            .map(|round| Some(self.score(round.opponent, round.response?) as u32))
+            .sum()
+    }
+
+    /// The total score if the second column is the desired outcome, or `None` if any of the rounds
+    /// has no such interpretation
+    pub fn total(&self, rounds: &[GameRound]) -> Option<u32> {
+        rounds
+            .iter()
+            .map(|round| {
+                let response = self.respond_to(round.opponent, round.strategy?);
+                Some(self.score(round.opponent, response) as u32)
+            })
+            .sum()
+    }
+}
+
+/// A line of a strategy guide for any [Game], with each interpretation of the second column that
+/// its code supports. In the puzzle every code has both.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct GameRound {
+    pub opponent: usize,
+    /// The shape to play if the second column is a shape
+    pub response: Option<usize>,
+    /// The desired outcome if the second column is a strategy
+    pub strategy: Option<ResponseStrategy>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::Game;
+    use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+    use proptest::prelude::*;
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let shape = |name| game.shape_named(name).unwrap();
+        let victories = [
+            ("Scissors", "Paper"),
+            ("Paper", "Rock"),
+            ("Rock", "Lizard"),
+            ("Lizard", "Spock"),
+            ("Spock", "Scissors"),
+            ("Scissors", "Lizard"),
+            ("Lizard", "Paper"),
+            ("Paper", "Spock"),
+            ("Spock", "Rock"),
+            ("Rock", "Scissors"),
+        ];
+
+        for (winner, loser) in victories {
+            assert_eq!(game.outcome(shape(loser), shape(winner)), Win);
+            assert_eq!(game.outcome(shape(winner), shape(loser)), Lose);
+        }
+    }
+
+    #[test]
+    fn custom_scoring() {
+        let game = Game::rock_paper_scissors_lizard_spock()
+            .with_shape_scores(&[1, 1, 1, 5, 2])
+            .unwrap()
+            .with_outcome_scores(0, 1, 10);
+
+        let rock = game.shape_named("Rock").unwrap();
+        let response = game.respond_to(rock, Win);
+
+        assert_eq!(game.shape_name(response), "Spock");
+        assert_eq!(game.score(rock, response), 15);
+        assert!(Game::new(&["Rock", "Paper"]).is_err());
+        assert!(Game::new(&["Rock", "Rock", "Paper"]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn every_shape_beats_half_of_the_others(shapes in (1..6usize).prop_map(|n| 2 * n + 1)) {
+            let names = (0..shapes).map(|shape| shape.to_string()).collect::<Vec<String>>();
+            let game = Game::new(&names.iter().map(String::as_str).collect::<Vec<&str>>())
+                .unwrap();
+
+            for opponent in 0..shapes {
+                let wins = (0..shapes)
+                    .filter(|player| game.outcome(opponent, *player) == Win)
+                    .count();
+                prop_assert_eq!(wins, shapes / 2);
+                for player in 0..shapes {
+                    let reversed = match game.outcome(opponent, player) {
+                        Lose => Win,
+                        Draw => Draw,
+                        Win => Lose,
+                    };
+                    prop_assert_eq!(game.outcome(player, opponent), reversed);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,315 @@
+/// --- Day 2: Rock Paper Scissors ---
+/// https://adventofcode.com/2022/day/2
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+
+use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+use crate::y2022::day02::Shape::{Paper, Rock, Scissors};
+
+use crate::y2022::day02::parse::load_lines;
+use crate::{new_reader, Solution};
+use std::io::BufRead;
+
+mod cipher;
+mod game;
+mod optimal;
+mod parse;
+mod report;
+mod simulation;
+
+pub use cipher::Cipher;
+pub use game::{Game, GameRound};
+pub use optimal::{optimal_responses, Constraint, OptimalPlay};
+pub use parse::{Day2ParseError, Expected, InvalidGuide, UnknownCode};
+pub use report::{GuideReport, InterpretationSummary, RoundBreakdown};
+pub use simulation::{FrequencyPredictor, MarkovPredictor, Predictor, SimulatedRound, Simulation};
+
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+lazy_static! {
+    /// The rules of the puzzle, in which the shapes are at the positions given by [Shape::index]
+    static ref CLASSIC_GAME: Game = Game::rock_paper_scissors();
+    static ref CLASSIC_CIPHER: Cipher = Cipher::classic();
+}
+
+impl Shape {
+    pub fn beats(&self) -> Self {
+        Lose.respond_to(self)
+    }
+
+    pub fn beaten_by(&self) -> Self {
+        Win.respond_to(self)
+    }
+
+    pub fn value(&self) -> u16 {
+        CLASSIC_GAME.shape_score(self.index())
+    }
+
+    /// The position of the shape in [Game::rock_paper_scissors]
+    pub fn index(&self) -> usize {
+        match self {
+            Rock => 0,
+            Paper => 1,
+            Scissors => 2,
+        }
+    }
+}
+
+const SHAPES: [Shape; 3] = [Rock, Paper, Scissors];
+
+impl FromStr for Shape {
+    type Err = UnknownCode;
+
+    /// Decipher either an opponent's code or a response code
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CLASSIC_CIPHER
+            .opponent_shape(s)
+            .or_else(|| CLASSIC_CIPHER.response_shape(s))
+            .map(|index| SHAPES[index])
+            .ok_or_else(|| UnknownCode(s.to_string()))
+    }
+}
+
+impl Display for Shape {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(CLASSIC_GAME.shape_name(self.index()))
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ResponseStrategy {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl ResponseStrategy {
+    pub fn respond_to(&self, opponent: &Shape) -> Shape {
+        SHAPES[CLASSIC_GAME.respond_to(opponent.index(), *self)]
+    }
+}
+
+impl FromStr for ResponseStrategy {
+    type Err = UnknownCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        CLASSIC_CIPHER
+            .strategy(s)
+            .ok_or_else(|| UnknownCode(s.to_string()))
+    }
+}
+
+impl Display for ResponseStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Lose => "Lose",
+            Draw => "Draw",
+            Win => "Win",
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Round {
+    opponent_shape: Shape,
+    potential_response: Shape,
+    response_strategy: ResponseStrategy,
+}
+
+impl Round {
+    pub fn naïve_score(&self) -> u16 {
+        score(&self.opponent_shape, &self.potential_response)
+    }
+
+    pub fn score(&self) -> u16 {
+        let response = self.response_strategy.respond_to(&self.opponent_shape);
+        score(&self.opponent_shape, &response)
+    }
+}
+
+impl Display for Round {
+    /// Encrypt the round in the format of the strategy guide
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (opponent_code, response_code) = CLASSIC_CIPHER
+            .encipher(self.opponent_shape.index(), self.potential_response.index())
+            .ok_or(std::fmt::Error)?;
+        write!(f, "{} {}", opponent_code, response_code)
+    }
+}
+
+impl FromStr for Round {
+    type Err = Day2ParseError;
+
+    /// Parse a single line of the strategy guide. Errors are reported as being on line 1.
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        parse_round(line, 1)
+    }
+}
+
+/// Parse one line of the strategy guide with the puzzle's cipher, reporting the first problem
+/// with its position
+fn parse_round(line: &str, line_number: usize) -> Result<Round, Day2ParseError> {
+    let round = CLASSIC_CIPHER.decipher_line(line, line_number)?;
+    // every code in the puzzle's cipher stands for both a shape and a strategy
+    Ok(Round {
+        opponent_shape: SHAPES[round.opponent],
+        potential_response: SHAPES[round.response.expect("Response code")],
+        response_strategy: round.strategy.expect("Strategy code"),
+    })
+}
+
+/// The player's score for a round of the puzzle's game
+fn score(opponent: &Shape, player: &Shape) -> u16 {
+    CLASSIC_GAME.score(opponent.index(), player.index())
+}
+
+/// The result of a round of the puzzle's game from the point of view of the player
+fn outcome(opponent: &Shape, player: &Shape) -> ResponseStrategy {
+    CLASSIC_GAME.outcome(opponent.index(), player.index())
+}
+
+pub fn get_input() -> Result<Vec<Round>, InvalidGuide> {
+    parse(new_reader("2022/day-02.txt"))
+}
+
+/// The total score if the second column is the shape to play
+pub fn part1(rounds: &[Round]) -> u16 {
+    rounds.iter().map(Round::naïve_score).sum()
+}
+
+/// The total score if the second column is the desired outcome
+pub fn part2(rounds: &[Round]) -> u16 {
+    rounds.iter().map(Round::score).sum()
+}
+
+/// Parse a strategy guide, one round per line. Each line must contain exactly two codes separated
+/// by whitespace.
+pub fn parse<R: BufRead>(reader: R) -> Result<Vec<Round>, InvalidGuide> {
+    load(reader).map_err(InvalidGuide)
+}
+
+/// Parse an entire strategy guide, reporting every line that cannot be read or parsed rather than
+/// stopping at the first
+pub fn load<R: BufRead>(reader: R) -> Result<Vec<Round>, Vec<Day2ParseError>> {
+    load_lines(reader, parse_round)
+}
+
+/// Rock Paper Scissors
+pub struct RockPaperScissors;
+
+impl Solution for RockPaperScissors {
+    type Input = Vec<Round>;
+    type Part1 = u16;
+    type Part2 = u16;
+    type Error = InvalidGuide;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        parse(reader)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+    use crate::y2022::day02::Shape::{Paper, Rock, Scissors};
+    use crate::y2022::day02::{
+        get_input, parse, ResponseStrategy, RockPaperScissors, Round, Shape, UnknownCode,
+    };
+    use crate::Solution;
+    use proptest::prelude::*;
+
+    /// A round as it could appear in the strategy guide. Both interpretations of the second column
+    /// are derived from the same code.
+    pub(super) fn round() -> impl Strategy<Value = Round> {
+        let shape = prop_oneof![Just(Rock), Just(Paper), Just(Scissors)];
+        (shape, 0..3usize).prop_map(|(opponent_shape, code)| Round {
+            opponent_shape,
+            potential_response: [Rock, Paper, Scissors][code],
+            response_strategy: [Lose, Draw, Win][code],
+        })
+    }
+
+    #[test]
+    fn part1() {
+        let result = super::part1(&get_input().unwrap());
+
+        println!("Part 1: {}", result);
+    }
+
+    #[test]
+    fn part2() {
+        let result = super::part2(&get_input().unwrap());
+
+        println!("Part 2: {}", result);
+    }
+
+    #[test]
+    fn parse_from_string() {
+        let result: u16 = parse("A Y\nB X\nC Z\n".as_bytes())
+            .unwrap()
+            .iter()
+            .map(|round| round.score())
+            .sum();
+
+        assert_eq!(result, 12);
+    }
+
+    #[test]
+    fn invalid_guide_is_an_error() {
+        let error = RockPaperScissors::parse("A Y\nB Q\n".as_bytes()).unwrap_err();
+
+        assert_eq!(error.0.len(), 1);
+        assert_eq!(error.0[0].line_number(), 2);
+        assert_eq!(
+            error.to_string(),
+            "1 invalid line(s) in the strategy guide\n\
+             Line 2, column 3: expected a response or strategy code, found \"Q\""
+        );
+    }
+
+    #[test]
+    fn codes_parse_individually() {
+        assert_eq!("A".parse::<Shape>(), Ok(Rock));
+        assert_eq!("Y".parse::<Shape>(), Ok(Paper));
+        assert_eq!("Z".parse::<ResponseStrategy>(), Ok(Win));
+        assert_eq!("D".parse::<Shape>(), Err(UnknownCode("D".to_string())));
+        assert_eq!(
+            "A".parse::<ResponseStrategy>(),
+            Err(UnknownCode("A".to_string()))
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn round_round_trips(round in round()) {
+            prop_assert_eq!(round.to_string().parse::<Round>(), Ok(round));
+        }
+        #[test]
+        fn strategy_achieves_desired_outcome(round in round()) {
+            let response = round.response_strategy.respond_to(&round.opponent_shape);
+            let outcome_score = round.score() - response.value();
+
+            let expected = match round.response_strategy {
+                Lose => 0,
+                Draw => 3,
+                Win => 6,
+            };
+            prop_assert_eq!(outcome_score, expected);
+        }
+    }
+}
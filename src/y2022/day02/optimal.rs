@@ -0,0 +1,368 @@
+use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+use crate::y2022::day02::{Game, Shape, CLASSIC_GAME, SHAPES};
+
+/// A restriction on the responses available over a whole tournament
+#[derive(Clone, Debug)]
+pub enum Constraint {
+    /// Exactly this many rounds must end in each outcome
+    Outcomes {
+        losses: usize,
+        draws: usize,
+        wins: usize,
+    },
+    /// Each shape, by position in the game, may be played at most this many times
+    ShapeLimits(Vec<usize>),
+}
+
+/// The best sequence of responses to a known opponent under a [Constraint]
+#[derive(PartialEq, Eq, Debug)]
+pub struct OptimalPlay {
+    pub responses: Vec<usize>,
+    pub score: u32,
+}
+
+impl Game {
+    /// The score-maximising responses to the opponent's shapes that satisfy the constraint, or
+    /// `None` if no sequence of responses can satisfy it.
+    ///
+    /// Rounds against the same shape are interchangeable, so this is a transportation problem from
+    /// the opponent's shapes to the outcomes or shapes being rationed. It is solved as a minimum
+    /// cost flow in a network with a node per shape rather than per round, so long tournaments
+    /// are cheap to solve.
+    pub fn optimal_play(&self, opponent: &[usize], constraint: &Constraint) -> Option<OptimalPlay> {
+        let mut supplies = vec![0; self.shape_count()];
+        for shape in opponent {
+            supplies[*shape] += 1;
+        }
+        let capacities = match constraint {
+            Constraint::Outcomes {
+                losses,
+                draws,
+                wins,
+            } if losses + draws + wins == opponent.len() => vec![*losses, *draws, *wins],
+            Constraint::ShapeLimits(limits) if limits.len() == self.shape_count() => limits.clone(),
+            _ => return None,
+        };
+        // The targets are either outcomes or shapes, depending on what is rationed
+        let response = |shape, target| match constraint {
+            Constraint::Outcomes { .. } => self.respond_to(shape, [Lose, Draw, Win][target]),
+            Constraint::ShapeLimits(_) => target,
+        };
+        let mut allocation = transport(&supplies, &capacities, |shape, target| {
+            self.score(shape, response(shape, target))
+        })?;
+        let mut play = OptimalPlay {
+            responses: Vec::with_capacity(opponent.len()),
+            score: 0,
+        };
+        for shape in opponent {
+            let target = allocation[*shape]
+                .iter()
+                .position(|count| *count > 0)
+                .expect("Every round is allocated");
+            allocation[*shape][target] -= 1;
+            let response = response(*shape, target);
+            play.responses.push(response);
+            play.score += self.score(*shape, response) as u32;
+        }
+        Some(play)
+    }
+}
+
+/// The highest-scoring responses to the opponent's shapes in the puzzle's game under a constraint
+pub fn optimal_responses(opponent: &[Shape], constraint: &Constraint) -> Option<(Vec<Shape>, u32)> {
+    let opponent = opponent.iter().map(Shape::index).collect::<Vec<usize>>();
+    let play = CLASSIC_GAME.optimal_play(&opponent, constraint)?;
+    let responses = play.responses.iter().map(|index| SHAPES[*index]).collect();
+    Some((responses, play.score))
+}
+
+/// Ship every unit of supply to a target without exceeding any target's capacity, maximising the
+/// total score. Returns the number of units shipped from each source to each target, or `None` if
+/// the capacity is insufficient.
+fn transport<S: Fn(usize, usize) -> u16>(
+    supplies: &[usize],
+    capacities: &[usize],
+    score: S,
+) -> Option<Vec<Vec<usize>>> {
+    let source = 0;
+    let sink = 1;
+    let source_node = |index: usize| 2 + index;
+    let target_node = |index: usize| 2 + supplies.len() + index;
+    let mut network = FlowNetwork::new(2 + supplies.len() + capacities.len());
+    for (index, supply) in supplies.iter().enumerate() {
+        network.add_edge(source, source_node(index), *supply, 0);
+    }
+    for (index, capacity) in capacities.iter().enumerate() {
+        network.add_edge(target_node(index), sink, *capacity, 0);
+    }
+    let mut routes = vec![vec![0; capacities.len()]; supplies.len()];
+    for (from, route) in routes.iter_mut().enumerate() {
+        for (to, edge) in route.iter_mut().enumerate() {
+            *edge = network.add_edge(
+                source_node(from),
+                target_node(to),
+                supplies[from],
+                -(score(from, to) as i64),
+            );
+        }
+    }
+    if network.min_cost_max_flow(source, sink) < supplies.iter().sum() {
+        return None;
+    }
+    Some(
+        routes
+            .iter()
+            .map(|route| route.iter().map(|edge| network.flow(*edge)).collect())
+            .collect(),
+    )
+}
+
+struct FlowEdge {
+    to: usize,
+    capacity: i64,
+    flow: i64,
+    cost: i64,
+}
+
+/// A network for minimum cost flow by successive shortest paths. Edges are stored in pairs so that
+/// the residual of edge `e` is `e ^ 1`.
+struct FlowNetwork {
+    edges: Vec<FlowEdge>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl FlowNetwork {
+    fn new(nodes: usize) -> Self {
+        Self {
+            edges: vec![],
+            adjacency: vec![vec![]; nodes],
+        }
+    }
+
+    /// Add an edge and its residual, returning the index of the forward edge
+    fn add_edge(&mut self, from: usize, to: usize, capacity: usize, cost: i64) -> usize {
+        let index = self.edges.len();
+        self.edges.push(FlowEdge {
+            to,
+            capacity: capacity as i64,
+            flow: 0,
+            cost,
+        });
+        self.edges.push(FlowEdge {
+            to: from,
+            capacity: 0,
+            flow: 0,
+            cost: -cost,
+        });
+        self.adjacency[from].push(index);
+        self.adjacency[to].push(index + 1);
+        index
+    }
+
+    /// The additional flow an edge can carry. Residual edges carry negative flow.
+    fn residual(&self, edge: usize) -> i64 {
+        self.edges[edge].capacity - self.edges[edge].flow
+    }
+
+    fn flow(&self, edge: usize) -> usize {
+        self.edges[edge].flow as usize
+    }
+
+    /// Push as much flow as possible from the source to the sink, always along the cheapest
+    /// remaining path, and return the amount pushed
+    fn min_cost_max_flow(&mut self, source: usize, sink: usize) -> usize {
+        let mut total = 0;
+        loop {
+            // Bellman-Ford, since the residual network has negative costs
+            let mut distances = vec![i64::MAX; self.adjacency.len()];
+            let mut via = vec![None; self.adjacency.len()];
+            distances[source] = 0;
+            let mut updated = true;
+            while updated {
+                updated = false;
+                for node in 0..self.adjacency.len() {
+                    if distances[node] == i64::MAX {
+                        continue;
+                    }
+                    for edge in &self.adjacency[node] {
+                        let FlowEdge { to, cost, .. } = self.edges[*edge];
+                        if self.residual(*edge) > 0 && distances[node] + cost < distances[to] {
+                            distances[to] = distances[node] + cost;
+                            via[to] = Some(*edge);
+                            updated = true;
+                        }
+                    }
+                }
+            }
+            if distances[sink] == i64::MAX {
+                return total;
+            }
+            let mut path = vec![];
+            let mut node = sink;
+            while let Some(edge) = via[node] {
+                path.push(edge);
+                node = self.edges[edge ^ 1].to;
+            }
+            let amount = path
+                .iter()
+                .map(|edge| self.residual(*edge))
+                .min()
+                .expect("The sink is reachable");
+            for edge in path {
+                self.edges[edge].flow += amount;
+                self.edges[edge ^ 1].flow -= amount;
+            }
+            total += amount as usize;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+    use crate::y2022::day02::Shape::{Paper, Rock, Scissors};
+    use crate::y2022::day02::{optimal_responses, Constraint, Game, OptimalPlay};
+    use proptest::prelude::*;
+
+    /// The best score for any sequence of responses satisfying the constraint, found by trying
+    /// every one of them
+    fn exhaustive_best(game: &Game, opponent: &[usize], constraint: &Constraint) -> Option<u32> {
+        let shapes = game.shape_count();
+        (0..shapes.pow(opponent.len() as u32))
+            .map(|mut code| {
+                (0..opponent.len())
+                    .map(|_| {
+                        let shape = code % shapes;
+                        code /= shapes;
+                        shape
+                    })
+                    .collect::<Vec<usize>>()
+            })
+            .filter(|responses| satisfies(game, opponent, responses, constraint))
+            .map(|responses| {
+                opponent
+                    .iter()
+                    .zip(responses)
+                    .map(|(opponent, response)| game.score(*opponent, response) as u32)
+                    .sum()
+            })
+            .max()
+    }
+
+    fn satisfies(
+        game: &Game,
+        opponent: &[usize],
+        responses: &[usize],
+        constraint: &Constraint,
+    ) -> bool {
+        let count = |predicate: &dyn Fn(usize, usize) -> bool| {
+            opponent
+                .iter()
+                .zip(responses)
+                .filter(|(opponent, response)| predicate(**opponent, **response))
+                .count()
+        };
+        match constraint {
+            Constraint::Outcomes {
+                losses,
+                draws,
+                wins,
+            } => [(Lose, losses), (Draw, draws), (Win, wins)]
+                .iter()
+                .all(|(outcome, expected)| {
+                    count(&|opponent, response| game.outcome(opponent, response) == *outcome)
+                        == **expected
+                }),
+            Constraint::ShapeLimits(limits) => limits
+                .iter()
+                .enumerate()
+                .all(|(shape, limit)| count(&|_, response| response == shape) <= *limit),
+        }
+    }
+
+    fn constraint() -> impl Strategy<Value = Constraint> {
+        prop_oneof![
+            (0..4usize, 0..4usize, 0..4usize).prop_map(|(losses, draws, wins)| {
+                Constraint::Outcomes {
+                    losses,
+                    draws,
+                    wins,
+                }
+            }),
+            proptest::collection::vec(0..5usize, 3).prop_map(Constraint::ShapeLimits),
+        ]
+    }
+
+    #[test]
+    fn optimal_play_with_fixed_outcomes() {
+        let constraint = Constraint::Outcomes {
+            losses: 1,
+            draws: 1,
+            wins: 1,
+        };
+
+        let (responses, score) = optimal_responses(&[Rock, Rock, Scissors], &constraint).unwrap();
+
+        // A draw is worth more against scissors than against rock, and a win is worth more against
+        // rock than against scissors
+        assert_eq!(responses, vec![Scissors, Paper, Scissors]);
+        assert_eq!(score, 3 + 8 + 6);
+    }
+
+    #[test]
+    fn optimal_play_with_shape_limits() {
+        let game = Game::rock_paper_scissors();
+
+        let play = game
+            .optimal_play(&[0, 0, 0], &Constraint::ShapeLimits(vec![0, 2, 5]))
+            .unwrap();
+
+        assert_eq!(
+            play,
+            OptimalPlay {
+                responses: vec![1, 1, 2],
+                score: 8 + 8 + 3
+            }
+        );
+    }
+
+    #[test]
+    fn impossible_constraints() {
+        let game = Game::rock_paper_scissors();
+        let outcomes = Constraint::Outcomes {
+            losses: 0,
+            draws: 0,
+            wins: 1,
+        };
+
+        assert_eq!(game.optimal_play(&[0, 1], &outcomes), None);
+        assert_eq!(
+            game.optimal_play(&[0, 1], &Constraint::ShapeLimits(vec![1, 0, 0])),
+            None
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn optimal_play_is_optimal(
+            opponent in proptest::collection::vec(0..3usize, 0..7),
+            constraint in constraint(),
+        ) {
+            let game = Game::new(&["Rock", "Paper", "Scissors"])
+                .unwrap()
+                .with_shape_scores(&[2, 7, 1])
+                .unwrap()
+                .with_outcome_scores(1, 2, 4);
+            let play = game.optimal_play(&opponent, &constraint);
+
+            prop_assert_eq!(
+                play.as_ref().map(|play| play.score),
+                exhaustive_best(&game, &opponent, &constraint)
+            );
+            if let Some(play) = play {
+                prop_assert!(satisfies(&game, &opponent, &play.responses, &constraint));
+            }
+        }
+    }
+}
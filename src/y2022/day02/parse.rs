@@ -0,0 +1,265 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{BufRead, ErrorKind};
+
+/// The token a [Day2ParseError] was expecting
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum Expected {
+    OpponentShape,
+    StrategyCode,
+    EndOfLine,
+}
+
+impl Display for Expected {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Expected::OpponentShape => "the opponent's shape",
+            Expected::StrategyCode => "a response or strategy code",
+            Expected::EndOfLine => "the end of the line",
+        })
+    }
+}
+
+/// A line of the strategy guide that could not be parsed
+#[derive(PartialEq, Eq, Debug)]
+pub enum Day2ParseError {
+    /// A token is missing, not recognised, or not expected
+    Token {
+        /// The line in the guide, starting from one
+        line_number: usize,
+        /// The character position of the offending token in the line, starting from one. If a
+        /// token is missing, this is just past the end of the line.
+        column: usize,
+        /// The offending token, or `None` if the line ended too early
+        token: Option<String>,
+        expected: Expected,
+    },
+    /// The line could not be read, for example because it is not valid UTF-8
+    Unreadable { line_number: usize, message: String },
+}
+
+impl Day2ParseError {
+    /// The line in the guide, starting from one
+    pub fn line_number(&self) -> usize {
+        match self {
+            Day2ParseError::Token { line_number, .. } => *line_number,
+            Day2ParseError::Unreadable { line_number, .. } => *line_number,
+        }
+    }
+}
+
+impl Display for Day2ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Day2ParseError::Token {
+                line_number,
+                column,
+                token,
+                expected,
+            } => {
+                write!(
+                    f,
+                    "Line {}, column {}: expected {}, ",
+                    line_number, column, expected
+                )?;
+                match token {
+                    Some(token) => write!(f, "found {:?}", token),
+                    None => write!(f, "found the end of the line"),
+                }
+            }
+            Day2ParseError::Unreadable {
+                line_number,
+                message,
+            } => write!(f, "Line {}: {}", line_number, message),
+        }
+    }
+}
+
+impl Error for Day2ParseError {}
+
+/// Every line of a strategy guide that could not be read or parsed
+#[derive(PartialEq, Eq, Debug)]
+pub struct InvalidGuide(pub Vec<Day2ParseError>);
+
+impl Display for InvalidGuide {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} invalid line(s) in the strategy guide", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InvalidGuide {}
+
+/// A code that does not stand for any shape or outcome in the puzzle's cipher
+#[derive(PartialEq, Eq, Debug)]
+pub struct UnknownCode(pub String);
+
+impl Display for UnknownCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown code {:?}", self.0)
+    }
+}
+
+impl Error for UnknownCode {}
+
+/// The whitespace-separated tokens in a line, each with the column at which it starts
+fn tokens(line: &str) -> Vec<(usize, &str)> {
+    let mut result = vec![];
+    let mut start = None;
+    for (column, (offset, character)) in line.char_indices().enumerate() {
+        match (start, character.is_whitespace()) {
+            (None, false) => start = Some((column + 1, offset)),
+            (Some((column, begin)), true) => {
+                result.push((column, &line[begin..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some((column, begin)) = start {
+        result.push((column, &line[begin..]));
+    }
+    result
+}
+
+/// Split a line of a strategy guide into its two codes and decipher them, reporting the first
+/// problem with its position. Anything after the second code is an error rather than being
+/// ignored.
+pub(super) fn decipher_codes<O, S, F, G>(
+    line: &str,
+    line_number: usize,
+    opponent: F,
+    strategy: G,
+) -> Result<(O, S), Day2ParseError>
+where
+    F: FnOnce(&str) -> Option<O>,
+    G: FnOnce(&str) -> Option<S>,
+{
+    let error = |column, token: Option<&str>, expected| Day2ParseError::Token {
+        line_number,
+        column,
+        token: token.map(str::to_string),
+        expected,
+    };
+    let end_of_line = line.chars().count() + 1;
+    let mut tokens = tokens(line).into_iter();
+    let (column, opponent_code) = tokens
+        .next()
+        .ok_or_else(|| error(end_of_line, None, Expected::OpponentShape))?;
+    let opponent = opponent(opponent_code)
+        .ok_or_else(|| error(column, Some(opponent_code), Expected::OpponentShape))?;
+    let (column, strategy_code) = tokens
+        .next()
+        .ok_or_else(|| error(end_of_line, None, Expected::StrategyCode))?;
+    let strategy = strategy(strategy_code)
+        .ok_or_else(|| error(column, Some(strategy_code), Expected::StrategyCode))?;
+    if let Some((column, extra)) = tokens.next() {
+        return Err(error(column, Some(extra), Expected::EndOfLine));
+    }
+    Ok((opponent, strategy))
+}
+
+/// Parse every line of a strategy guide, reporting every line that cannot be read or parsed
+/// rather than stopping at the first
+pub(super) fn load_lines<R, T, F>(reader: R, parse_line: F) -> Result<Vec<T>, Vec<Day2ParseError>>
+where
+    R: BufRead,
+    F: Fn(&str, usize) -> Result<T, Day2ParseError>,
+{
+    let mut result = vec![];
+    let mut errors = vec![];
+    for (index, line) in reader.lines().enumerate() {
+        match line {
+            Ok(line) => match parse_line(&line, index + 1) {
+                Ok(item) => result.push(item),
+                Err(error) => errors.push(error),
+            },
+            Err(error) => {
+                let fatal = error.kind() != ErrorKind::InvalidData;
+                errors.push(Day2ParseError::Unreadable {
+                    line_number: index + 1,
+                    message: error.to_string(),
+                });
+                // the rest of the guide can still be read after a line of invalid UTF-8, but not
+                // necessarily after other errors
+                if fatal {
+                    break;
+                }
+            }
+        }
+    }
+    if errors.is_empty() {
+        Ok(result)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::{load, Day2ParseError, Expected};
+
+    #[test]
+    fn all_parse_errors_are_collected() {
+        let guide = "A Y\nB\nA  Q\nC Z\nD X\nA X C\n";
+
+        let errors = load(guide.as_bytes()).unwrap_err();
+
+        let expected = vec![
+            Day2ParseError::Token {
+                line_number: 2,
+                column: 2,
+                token: None,
+                expected: Expected::StrategyCode,
+            },
+            Day2ParseError::Token {
+                line_number: 3,
+                column: 4,
+                token: Some("Q".to_string()),
+                expected: Expected::StrategyCode,
+            },
+            Day2ParseError::Token {
+                line_number: 5,
+                column: 1,
+                token: Some("D".to_string()),
+                expected: Expected::OpponentShape,
+            },
+            Day2ParseError::Token {
+                line_number: 6,
+                column: 5,
+                token: Some("C".to_string()),
+                expected: Expected::EndOfLine,
+            },
+        ];
+        assert_eq!(errors, expected);
+        assert_eq!(
+            errors[1].to_string(),
+            "Line 3, column 4: expected a response or strategy code, found \"Q\""
+        );
+        assert_eq!(load("A Y\nC Z\n".as_bytes()).unwrap().len(), 2);
+        assert_eq!(
+            load("\tA  Y\u{e9} \n".as_bytes()).unwrap_err(),
+            vec![Day2ParseError::Token {
+                line_number: 1,
+                column: 5,
+                token: Some("Y\u{e9}".to_string()),
+                expected: Expected::StrategyCode,
+            }]
+        );
+    }
+
+    #[test]
+    fn unreadable_lines_are_collected() {
+        let errors = load(&b"A Y\n\xff\nB X\nB\n"[..]).unwrap_err();
+
+        let line_numbers = errors
+            .iter()
+            .map(Day2ParseError::line_number)
+            .collect::<Vec<usize>>();
+        assert_eq!(line_numbers, vec![2, 4]);
+        assert!(matches!(errors[0], Day2ParseError::Unreadable { .. }));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::y2022::day02::ResponseStrategy::{Draw, Lose, Win};
+use crate::y2022::day02::{outcome, score, Round, Shape, CLASSIC_GAME};
+use std::fmt::{Display, Formatter};
+
+/// Totals for one interpretation of the strategy guide
+#[derive(Default, PartialEq, Eq, Debug)]
+pub struct InterpretationSummary {
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    /// The points awarded for the shapes played
+    pub shape_points: u32,
+    /// The points awarded for the outcomes of the rounds
+    pub outcome_points: u32,
+}
+
+impl InterpretationSummary {
+    fn add(&mut self, opponent: &Shape, response: &Shape) {
+        let outcome = outcome(opponent, response);
+        match outcome {
+            Lose => self.losses += 1,
+            Draw => self.draws += 1,
+            Win => self.wins += 1,
+        }
+        self.shape_points += response.value() as u32;
+        self.outcome_points += CLASSIC_GAME.outcome_score(outcome) as u32;
+    }
+
+    pub fn total(&self) -> u32 {
+        self.shape_points + self.outcome_points
+    }
+}
+
+/// How a single round of the guide plays out under each interpretation
+#[derive(PartialEq, Eq, Debug)]
+pub struct RoundBreakdown {
+    /// The position of the round in the guide, starting from one
+    pub line_number: usize,
+    pub opponent: Shape,
+    /// The shape played if the second column is the shape to play
+    pub naïve_response: Shape,
+    /// The shape played if the second column is the desired outcome
+    pub decrypted_response: Shape,
+}
+
+impl RoundBreakdown {
+    /// Whether the two interpretations play different shapes in this round
+    pub fn disagrees(&self) -> bool {
+        self.naïve_response != self.decrypted_response
+    }
+}
+
+/// An audit of a strategy guide under both interpretations of the second column
+pub struct GuideReport {
+    pub naïve: InterpretationSummary,
+    pub decrypted: InterpretationSummary,
+    pub rounds: Vec<RoundBreakdown>,
+}
+
+impl GuideReport {
+    pub fn new(guide: &[Round]) -> Self {
+        let mut naïve = InterpretationSummary::default();
+        let mut decrypted = InterpretationSummary::default();
+        let mut rounds = Vec::with_capacity(guide.len());
+        for (index, round) in guide.iter().enumerate() {
+            let opponent = round.opponent_shape;
+            let decrypted_response = round.response_strategy.respond_to(&opponent);
+            naïve.add(&opponent, &round.potential_response);
+            decrypted.add(&opponent, &decrypted_response);
+            rounds.push(RoundBreakdown {
+                line_number: index + 1,
+                opponent,
+                naïve_response: round.potential_response,
+                decrypted_response,
+            });
+        }
+        Self {
+            naïve,
+            decrypted,
+            rounds,
+        }
+    }
+
+    /// The rounds in which the two interpretations play different shapes
+    pub fn disagreements(&self) -> impl Iterator<Item = &RoundBreakdown> {
+        self.rounds.iter().filter(|round| round.disagrees())
+    }
+
+    /// Every round with its shapes, outcomes and scores under both interpretations, as
+    /// comma-separated values with a header row
+    pub fn to_csv(&self) -> String {
+        let mut csv = "line,opponent,naive_response,naive_outcome,naive_score,\
+            decrypted_response,decrypted_outcome,decrypted_score,disagrees\n"
+            .to_string();
+        for round in &self.rounds {
+            let interpretation = |response: &Shape| {
+                format!(
+                    "{},{},{}",
+                    response,
+                    outcome(&round.opponent, response),
+                    score(&round.opponent, response)
+                )
+            };
+            csv.push_str(&format!(
+                "{},{},{},{},{}\n",
+                round.line_number,
+                round.opponent,
+                interpretation(&round.naïve_response),
+                interpretation(&round.decrypted_response),
+                round.disagrees()
+            ));
+        }
+        csv
+    }
+}
+
+impl Display for GuideReport {
+    /// A summary of each interpretation followed by the rounds in which they disagree
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<10}{:>6}{:>7}{:>8}{:>14}{:>16}{:>7}",
+            "", "Wins", "Draws", "Losses", "Shape points", "Outcome points", "Total"
+        )?;
+        for (label, summary) in [("Naïve", &self.naïve), ("Decrypted", &self.decrypted)] {
+            writeln!(
+                f,
+                "{:<10}{:>6}{:>7}{:>8}{:>14}{:>16}{:>7}",
+                label,
+                summary.wins,
+                summary.draws,
+                summary.losses,
+                summary.shape_points,
+                summary.outcome_points,
+                summary.total()
+            )?;
+        }
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:>6}  {:<10}{:<10}Decrypted",
+            "Line", "Opponent", "Naïve"
+        )?;
+        for round in self.disagreements() {
+            writeln!(
+                f,
+                "{:>6}  {:<10}{:<10}{}",
+                round.line_number, round.opponent, round.naïve_response, round.decrypted_response
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::tests::round;
+    use crate::y2022::day02::{parse, part1, part2, GuideReport, InterpretationSummary};
+    use proptest::prelude::*;
+
+    #[test]
+    fn guide_report_breaks_down_scores() {
+        let rounds = parse("A Y\nB X\nC Z\n".as_bytes()).unwrap();
+
+        let report = GuideReport::new(&rounds);
+
+        let expected_naïve = InterpretationSummary {
+            wins: 1,
+            draws: 1,
+            losses: 1,
+            shape_points: 6,
+            outcome_points: 9,
+        };
+        assert_eq!(report.naïve, expected_naïve);
+        assert_eq!(report.decrypted.shape_points, 3);
+        assert_eq!(report.decrypted.total(), 12);
+        let disagreements = report
+            .disagreements()
+            .map(|round| round.line_number)
+            .collect::<Vec<usize>>();
+        assert_eq!(disagreements, vec![1, 3]);
+    }
+
+    #[test]
+    fn guide_report_exports_tables() {
+        let rounds = parse("A Y\nB X\nC Z\n".as_bytes()).unwrap();
+
+        let report = GuideReport::new(&rounds);
+
+        assert_eq!(
+            report.to_string(),
+            "            Wins  Draws  Losses  Shape points  Outcome points  Total
+Naïve          1      1       1             6               9     15
+Decrypted      1      1       1             3               9     12
+
+  Line  Opponent  Naïve     Decrypted
+     1  Rock      Paper     Rock
+     3  Scissors  Scissors  Rock
+"
+        );
+        assert_eq!(
+            report.to_csv().lines().nth(2),
+            Some("2,Paper,Rock,Lose,1,Rock,Lose,1,false")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn guide_reports_agree_with_puzzle(rounds in proptest::collection::vec(round(), 0..20)) {
+            let report = GuideReport::new(&rounds);
+
+            prop_assert_eq!(report.naïve.total(), part1(&rounds) as u32);
+            prop_assert_eq!(report.decrypted.total(), part2(&rounds) as u32);
+            prop_assert_eq!(report.naïve.wins + report.naïve.draws + report.naïve.losses, rounds.len());
+        }
+    }
+}
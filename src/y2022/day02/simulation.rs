@@ -0,0 +1,213 @@
+use crate::y2022::day02::Shape::Rock;
+use crate::y2022::day02::{score, Round, Shape, SHAPES};
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
+
+/// Anticipates the opponent's next shape from the shapes they have played so far
+pub trait Predictor {
+    /// The shape the opponent is expected to play next, if there is enough history to tell
+    fn predict(&self) -> Option<Shape>;
+
+    /// Learn the shape the opponent actually played
+    fn observe(&mut self, shape: Shape);
+}
+
+/// The most common of the given shapes, preferring the earliest of Rock, Paper and Scissors on a tie
+fn most_common(counts: &[usize; 3]) -> Option<Shape> {
+    SHAPES
+        .iter()
+        .zip(counts)
+        .filter(|(_, count)| **count > 0)
+        .min_by_key(|(shape, count)| (Reverse(**count), shape.index()))
+        .map(|(shape, _)| *shape)
+}
+
+/// Predicts that the opponent will play the shape they have played most often
+#[derive(Default)]
+pub struct FrequencyPredictor {
+    counts: [usize; 3],
+}
+
+impl Predictor for FrequencyPredictor {
+    fn predict(&self) -> Option<Shape> {
+        most_common(&self.counts)
+    }
+
+    fn observe(&mut self, shape: Shape) {
+        self.counts[shape.index()] += 1;
+    }
+}
+
+/// Predicts the opponent's next shape from what followed their last few shapes in the past
+pub struct MarkovPredictor {
+    order: usize,
+    history: VecDeque<Shape>,
+    /// How often each shape followed each sequence of `order` shapes
+    transitions: HashMap<Vec<Shape>, [usize; 3]>,
+}
+
+impl MarkovPredictor {
+    /// A chain conditioned on the previous `order` shapes
+    pub fn new(order: usize) -> Self {
+        Self {
+            order,
+            history: VecDeque::with_capacity(order + 1),
+            transitions: HashMap::new(),
+        }
+    }
+}
+
+impl Predictor for MarkovPredictor {
+    fn predict(&self) -> Option<Shape> {
+        if self.history.len() < self.order {
+            return None;
+        }
+        let context = self.history.iter().copied().collect::<Vec<Shape>>();
+        most_common(self.transitions.get(&context)?)
+    }
+
+    fn observe(&mut self, shape: Shape) {
+        if self.history.len() == self.order {
+            let context = self.history.iter().copied().collect::<Vec<Shape>>();
+            self.transitions.entry(context).or_insert([0; 3])[shape.index()] += 1;
+            self.history.push_back(shape);
+            self.history.pop_front();
+        } else {
+            self.history.push_back(shape);
+        }
+    }
+}
+
+/// One round played by an adaptive player
+#[derive(PartialEq, Eq, Debug)]
+pub struct SimulatedRound {
+    /// What the player expected the opponent to play
+    pub prediction: Option<Shape>,
+    pub opponent: Shape,
+    /// The shape that beats the prediction, or rock if there was none
+    pub response: Shape,
+    pub score: u16,
+    /// The adaptive player's total score up to and including this round
+    pub cumulative_score: u32,
+    /// The total score from following the strategy guide up to and including this round
+    pub guide_cumulative_score: u32,
+}
+
+/// The rounds played by an adaptive player against a recorded opponent
+pub struct Simulation {
+    pub rounds: Vec<SimulatedRound>,
+}
+
+impl Simulation {
+    /// Play against the opponent's shapes from the strategy guide, predicting each one before
+    /// learning it
+    pub fn run<P: Predictor>(mut predictor: P, guide: &[Round]) -> Self {
+        let mut cumulative_score = 0;
+        let mut guide_cumulative_score = 0;
+        let mut rounds = Vec::with_capacity(guide.len());
+        for round in guide {
+            let prediction = predictor.predict();
+            let opponent = round.opponent_shape;
+            let response = prediction.map_or(Rock, |shape| shape.beaten_by());
+            let score = score(&opponent, &response);
+            cumulative_score += score as u32;
+            guide_cumulative_score += round.score() as u32;
+            predictor.observe(opponent);
+            rounds.push(SimulatedRound {
+                prediction,
+                opponent,
+                response,
+                score,
+                cumulative_score,
+                guide_cumulative_score,
+            });
+        }
+        Self { rounds }
+    }
+
+    /// The adaptive player's total score
+    pub fn score(&self) -> u32 {
+        self.rounds.last().map_or(0, |round| round.cumulative_score)
+    }
+
+    /// The total score from following the strategy guide instead
+    pub fn guide_score(&self) -> u32 {
+        self.rounds
+            .last()
+            .map_or(0, |round| round.guide_cumulative_score)
+    }
+
+    /// The number of rounds in which the opponent's shape was predicted correctly
+    pub fn correct_predictions(&self) -> usize {
+        self.rounds
+            .iter()
+            .filter(|round| round.prediction == Some(round.opponent))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::day02::Shape::{Paper, Rock, Scissors};
+    use crate::y2022::day02::{parse, FrequencyPredictor, MarkovPredictor, Predictor, Simulation};
+
+    #[test]
+    fn frequency_predictor() {
+        let mut predictor = FrequencyPredictor::default();
+        assert_eq!(predictor.predict(), None);
+
+        for shape in [Scissors, Paper, Scissors, Paper] {
+            predictor.observe(shape);
+        }
+
+        assert_eq!(predictor.predict(), Some(Paper));
+        predictor.observe(Scissors);
+        assert_eq!(predictor.predict(), Some(Scissors));
+    }
+
+    #[test]
+    fn markov_predictor_learns_cycles() {
+        let mut predictor = MarkovPredictor::new(1);
+        let cycle = [Rock, Paper, Scissors];
+        let mut predictions = vec![];
+
+        for shape in cycle.iter().cycle().take(7) {
+            predictions.push(predictor.predict());
+            predictor.observe(*shape);
+        }
+
+        assert_eq!(
+            predictions,
+            vec![
+                None,
+                None,
+                None,
+                None,
+                Some(Paper),
+                Some(Scissors),
+                Some(Rock)
+            ]
+        );
+    }
+
+    #[test]
+    fn simulation_against_guide() {
+        let guide = "A Y\nB X\nC Z\nB X\nC Z\nA Y\n";
+        let rounds = parse(guide.as_bytes()).unwrap();
+
+        let simulation = Simulation::run(MarkovPredictor::new(1), &rounds);
+
+        let cumulative_scores = simulation
+            .rounds
+            .iter()
+            .map(|round| (round.cumulative_score, round.guide_cumulative_score))
+            .collect::<Vec<(u32, u32)>>();
+        assert_eq!(
+            cumulative_scores,
+            vec![(4, 4), (5, 5), (12, 12), (13, 13), (20, 20), (23, 24)]
+        );
+        assert_eq!(simulation.correct_predictions(), 1);
+        assert_eq!(simulation.score(), 23);
+        assert_eq!(simulation.guide_score(), 24);
+    }
+}
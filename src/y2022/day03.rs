@@ -0,0 +1,300 @@
+/// --- Day 3: Rucksack Reorganization ---
+/// https://adventofcode.com/2022/day/3
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use crate::{lines, new_reader, Solution};
+use std::io::BufRead;
+
+/// A set of item types, stored as a bit mask in which bit `n` stands for the item with priority
+/// `n + 1`. Set operations take a single instruction and iteration is in order of priority.
+#[derive(Default, PartialEq, Eq, Copy, Clone, Debug)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item type to the set. Only the letters `a` to `z` and `A` to `Z` are item types.
+    pub fn insert(&mut self, item: char) -> Result<(), &'static str> {
+        self.0 |= bit(item).ok_or("Not an item type")?;
+        Ok(())
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        bit(item).is_some_and(|bit| self.0 & bit != 0)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The item types in the set, in order of priority
+    pub fn iter(&self) -> Items {
+        Items(self.0)
+    }
+
+    /// The only item type in the set
+    fn single(&self) -> Option<char> {
+        match self.len() {
+            1 => self.iter().next(),
+            _ => None,
+        }
+    }
+}
+
+/// The bit standing for an item type, if it is one
+fn bit(item: char) -> Option<u64> {
+    match item {
+        'a'..='z' | 'A'..='Z' => Some(1 << (priority(item) - 1)),
+        _ => None,
+    }
+}
+
+impl FromStr for ItemSet {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut result = Self::new();
+        for item in s.chars() {
+            result.insert(item)?;
+        }
+        Ok(result)
+    }
+}
+
+impl IntoIterator for ItemSet {
+    type Item = char;
+    type IntoIter = Items;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The item types remaining in a set, lowest priority first
+pub struct Items(u64);
+
+impl Iterator for Items {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        let item = if index < 26 {
+            b'a' + index as u8
+        } else {
+            b'A' + (index - 26) as u8
+        };
+        Some(item as char)
+    }
+}
+
+/// A container with supplies for a jungle journey. "Each rucksack has two large compartments. All
+/// items of a given type are meant to go into exactly one of the two compartments."
+#[derive(Clone)]
+pub struct Rucksack {
+    items: ItemSet,
+    compartments: (ItemSet, ItemSet),
+}
+
+impl Rucksack {
+    pub fn priority(&self) -> Result<u32, &'static str> {
+        let intersection = self.compartments.0.intersection(&self.compartments.1);
+        match intersection.len() {
+            0 => Err("No common items between the compartments"),
+            1 => Ok(priority(intersection.single().unwrap())),
+            _ => Err("Multiple common items between the compartments"),
+        }
+    }
+}
+
+/// A value to assist in item reärrangement
+fn priority(item: char) -> u32 {
+    let item = item as u32;
+    if item >= 'a' as u32 && item <= 'z' as u32 {
+        item - 'a' as u32 + 1
+    } else {
+        item - 'A' as u32 + 27
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if !s.is_ascii() {
+            return Err("Not an item type");
+        }
+        if s.len() % 2 != 0 {
+            return Err("The items cannot be evenly divided between the two compartments");
+        }
+        let compartments = s.split_at(s.len() / 2);
+        let compartments = (
+            compartments.0.parse::<ItemSet>()?,
+            compartments.1.parse::<ItemSet>()?,
+        );
+        Ok(Self {
+            items: compartments.0.union(&compartments.1),
+            compartments,
+        })
+    }
+}
+
+/// A group of elves' rucksacks. There is exactly one common item shared by each group member.
+pub struct Group {
+    members: (Rucksack, Rucksack, Rucksack),
+}
+
+impl Group {
+    /// The priority of shared item
+    pub fn badge_priority(&self) -> Result<u32, &'static str> {
+        let badge = self.badge()?;
+        Ok(priority(badge))
+    }
+    fn badge(&self) -> Result<char, &'static str> {
+        let intersection = self
+            .members
+            .0
+            .items
+            .intersection(&self.members.1.items)
+            .intersection(&self.members.2.items);
+        match intersection.len() {
+            0 => Err("No items in common between members of the group"),
+            1 => Ok(intersection.single().unwrap()),
+            _ => Err("Multiple items in common between members of the group"),
+        }
+    }
+}
+
+pub fn get_input() -> impl Iterator<Item = Rucksack> {
+    parse(new_reader("2022/day-03.txt"))
+}
+
+/// The sum of the priorities of the item types that appear in both compartments of each rucksack
+pub fn part1(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.priority().unwrap())
+        .sum()
+}
+
+/// The sum of the priorities of the badges of each group of three elves
+pub fn part2(rucksacks: &[Rucksack]) -> u32 {
+    rucksacks
+        .chunks(3)
+        .map(|chunk| match chunk {
+            [first, second, third] => Group {
+                members: (first.clone(), second.clone(), third.clone()),
+            },
+            _ => panic!("Group only has {} items", chunk.len()),
+        })
+        .map(|group| group.badge_priority())
+        .map(Result::unwrap)
+        .sum()
+}
+
+/// Parse the contents of each rucksack, one per line
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Rucksack> {
+    lines(reader)
+        .map(|line| line.parse::<Rucksack>())
+        .map(Result::unwrap)
+}
+
+/// Rucksack Reorganization
+pub struct RucksackReorganization;
+
+impl Solution for RucksackReorganization {
+    type Input = Vec<Rucksack>;
+    type Part1 = u32;
+    type Part2 = u32;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::y2022::day03::{get_input, parse, ItemSet, Rucksack};
+
+    #[test]
+    fn part1() {
+        let result = super::part1(&get_input().collect::<Vec<Rucksack>>());
+
+        println!("Part 1: {}", result);
+    }
+
+    #[test]
+    fn part2() {
+        let result = super::part2(&get_input().collect::<Vec<Rucksack>>());
+
+        println!("Part 2: {}", result);
+    }
+
+    #[test]
+    fn item_set_operations() {
+        let first = "abcXYZ".parse::<ItemSet>().unwrap();
+        let second = "cdZ".parse::<ItemSet>().unwrap();
+
+        assert_eq!(first.union(&second).iter().collect::<String>(), "abcdXYZ");
+        assert_eq!(first.intersection(&second).iter().collect::<String>(), "cZ");
+        assert_eq!(first.difference(&second).iter().collect::<String>(), "abXY");
+        assert_eq!(first.len(), 6);
+        assert!(first.contains('X'));
+        assert!(!first.contains('d'));
+        assert!(ItemSet::new().is_empty());
+        assert!("ab1".parse::<ItemSet>().is_err());
+    }
+
+    #[test]
+    fn item_sets_iterate_in_priority_order() {
+        let items = "ZzAa"
+            .parse::<ItemSet>()
+            .unwrap()
+            .into_iter()
+            .collect::<String>();
+
+        assert_eq!(items, "azAZ");
+    }
+
+    #[test]
+    fn parse_from_string() {
+        let input =
+            "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\n";
+        let rucksacks = parse(input.as_bytes()).collect::<Vec<Rucksack>>();
+
+        assert_eq!(super::part1(&rucksacks), 16 + 38 + 42);
+        assert_eq!(super::part2(&rucksacks), 18);
+    }
+}
@@ -1,12 +1,16 @@
 /// --- Day 4: Camp Cleanup ---
 /// https://adventofcode.com/2022/day/4
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use crate::get_lines;
+use crate::{lines, new_reader, Solution};
+use std::io::BufRead;
 
 pub type SectionId = u8;
 
 /// Someone responsible for cleaning a section of the camp
+#[derive(Debug, PartialEq, Eq)]
 pub struct Elf {
     lower_section_id: SectionId,
     upper_section_id: SectionId,
@@ -20,6 +24,12 @@ impl Elf {
     }
 }
 
+impl Display for Elf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.lower_section_id, self.upper_section_id)
+    }
+}
+
 impl FromStr for Elf {
     type Err = &'static str;
 
@@ -46,6 +56,7 @@ impl FromStr for Elf {
 }
 
 /// Two crew members responsible for cleaning part of the camp
+#[derive(Debug, PartialEq, Eq)]
 pub struct Pair(Elf, Elf);
 
 impl Pair {
@@ -64,6 +75,12 @@ impl Pair {
     }
 }
 
+impl Display for Pair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
 impl FromStr for Pair {
     type Err = &'static str;
 
@@ -85,29 +102,91 @@ impl FromStr for Pair {
 }
 
 pub fn get_input() -> impl Iterator<Item = Pair> {
-    get_lines("day-04.txt")
+    parse(new_reader("2022/day-04.txt"))
+}
+
+/// The number of pairs in which one elf's assignment fully contains the other's
+pub fn part1(pairs: &[Pair]) -> usize {
+    pairs
+        .iter()
+        .filter(|pair| pair.one_fully_contains_the_other())
+        .count()
+}
+
+/// The number of pairs with overlapping assignments
+pub fn part2(pairs: &[Pair]) -> usize {
+    pairs.iter().filter(|pair| pair.sections_overlap()).count()
+}
+
+/// Parse the section assignments, one pair of elves per line
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Pair> {
+    lines(reader)
         .map(|line| line.parse::<Pair>())
         .map(Result::unwrap)
 }
 
+/// Camp Cleanup
+pub struct CampCleanup;
+
+impl Solution for CampCleanup {
+    type Input = Vec<Pair>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::day04::{get_input, Pair};
+    use crate::y2022::day04::{get_input, Elf, Pair, SectionId};
+    use proptest::prelude::*;
+
+    fn elf() -> impl Strategy<Value = Elf> {
+        (any::<SectionId>(), any::<SectionId>()).prop_map(|(x, y)| Elf {
+            lower_section_id: x.min(y),
+            upper_section_id: x.max(y),
+        })
+    }
 
     #[test]
     fn part1() {
-        let result = get_input()
-            .filter(Pair::one_fully_contains_the_other)
-            .count();
+        let result = super::part1(&get_input().collect::<Vec<Pair>>());
 
         println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let result = get_input().filter(Pair::sections_overlap).count();
+        let result = super::part2(&get_input().collect::<Vec<Pair>>());
 
         println!("Part 2: {}", result);
     }
+
+    proptest! {
+        #[test]
+        fn pair_round_trips(x in elf(), y in elf()) {
+            let pair = Pair(x, y);
+
+            prop_assert_eq!(pair.to_string().parse::<Pair>(), Ok(pair));
+        }
+
+        #[test]
+        fn containment_implies_overlap(x in elf(), y in elf()) {
+            let pair = Pair(x, y);
+
+            prop_assert!(!pair.one_fully_contains_the_other() || pair.sections_overlap());
+        }
+    }
 }
@@ -0,0 +1,346 @@
+/// --- Day 5: ---
+/// https://adventofcode.com/2022/day/5
+use crate::{blocks, new_reader, Solution};
+use std::borrow::BorrowMut;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub fn get_part1_input() -> (Vec<VecDeque<char>>, Vec<CrateMover9000Instruction>) {
+    parse(new_reader("2022/day-05.txt"))
+}
+
+pub fn get_part2_input() -> (Vec<VecDeque<char>>, Vec<CrateMover9001Instruction>) {
+    parse(new_reader("2022/day-05.txt"))
+}
+
+/// Parse the starting stacks of crates and the rearrangement procedure for a specific model of
+/// crane
+pub fn parse<I, R>(reader: R) -> (Vec<VecDeque<char>>, Vec<I>)
+where
+    I: Instruction,
+    <I as FromStr>::Err: Debug,
+    R: BufRead,
+{
+    let mut iterator = blocks(reader);
+    let stacks = iterator.next().expect("Stack specification is missing");
+    let stacks = parse_stacks(&stacks);
+    let instructions = iterator.next().expect("Instructions missing");
+    let instructions = instructions
+        .split('\n')
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| line.parse::<I>())
+        .map(Result::unwrap)
+        .collect::<Vec<I>>();
+    (stacks, instructions)
+}
+
+fn parse_stacks(lines: &str) -> Vec<VecDeque<char>> {
+    let mut stacks = vec![VecDeque::new(); 9];
+    for line in lines.split('\n') {
+        let mut stack_index = None;
+        for (i, c) in line.chars().enumerate() {
+            if c == '[' {
+                stack_index = Some(i / 4);
+            } else if let Some(index) = stack_index {
+                stacks[index].push_back(c);
+                stack_index = None;
+            }
+        }
+    }
+    stacks
+}
+
+pub trait Instruction: FromStr {
+    fn execute(&self, stacks: Vec<VecDeque<char>>) -> Vec<VecDeque<char>>;
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CrateMover9000Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Display for CrateMover9000Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+impl FromStr for CrateMover9000Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut components = line.split(' ');
+        let count = components
+            .nth(1)
+            .ok_or_else(|| "count not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse count: {}", parse_error))?;
+        let from = components
+            .nth(1)
+            .ok_or_else(|| "Source stack not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse source stack: {}", parse_error))?
+            - 1;
+        let to = components
+            .nth(1)
+            .ok_or_else(|| "Destination stack not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse destination stack: {}", parse_error))?
+            - 1;
+        Ok(Self { count, from, to })
+    }
+}
+
+impl Instruction for CrateMover9000Instruction {
+    fn execute(&self, mut stacks: Vec<VecDeque<char>>) -> Vec<VecDeque<char>> {
+        let s: &mut [VecDeque<char>] = stacks.borrow_mut();
+        for _ in 0..self.count {
+            let tmp = s[self.from].pop_front();
+            s[self.to].push_front(
+                tmp.expect("CrateMover attempted to remove an item from an empty stack"),
+            );
+        }
+        s.to_vec()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CrateMover9001Instruction {
+    count: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Display for CrateMover9001Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.count,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+impl FromStr for CrateMover9001Instruction {
+    type Err = String;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut components = line.split(' ');
+        let count = components
+            .nth(1)
+            .ok_or_else(|| "count not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse count: {}", parse_error))?;
+        let from = components
+            .nth(1)
+            .ok_or_else(|| "Source stack not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse source stack: {}", parse_error))?
+            - 1;
+        let to = components
+            .nth(1)
+            .ok_or_else(|| "Destination stack not specified".to_string())?
+            .parse::<usize>()
+            .map_err(|parse_error| format!("Unable to parse destination stack: {}", parse_error))?
+            - 1;
+        Ok(Self { count, from, to })
+    }
+}
+
+impl From<&CrateMover9000Instruction> for CrateMover9001Instruction {
+    /// The same step of the rearrangement procedure, carried out by the newer crane
+    fn from(instruction: &CrateMover9000Instruction) -> Self {
+        Self {
+            count: instruction.count,
+            from: instruction.from,
+            to: instruction.to,
+        }
+    }
+}
+
+impl Instruction for CrateMover9001Instruction {
+    fn execute(&self, mut stacks: Vec<VecDeque<char>>) -> Vec<VecDeque<char>> {
+        let mut buffer = VecDeque::with_capacity(self.count);
+        for _ in 0..self.count {
+            buffer.push_front(
+                stacks[self.from]
+                    .pop_front()
+                    .expect("CrateMover attempted to remove an item from an empty stack"),
+            );
+        }
+        while let Some(item) = buffer.pop_front() {
+            stacks[self.to].push_front(item);
+        }
+        stacks
+    }
+}
+
+/// The crates on top of each stack after rearranging them with a CrateMover 9000
+pub fn part1(stacks: &[VecDeque<char>], instructions: &[CrateMover9000Instruction]) -> String {
+    rearrange(stacks, instructions)
+}
+
+/// The crates on top of each stack after rearranging them with a CrateMover 9001
+pub fn part2(stacks: &[VecDeque<char>], instructions: &[CrateMover9001Instruction]) -> String {
+    rearrange(stacks, instructions)
+}
+
+fn rearrange<I: Instruction>(stacks: &[VecDeque<char>], instructions: &[I]) -> String {
+    let mut stacks = stacks.to_vec();
+    for instruction in instructions {
+        stacks = instruction.execute(stacks);
+    }
+    summarise_stacks(&stacks)
+}
+
+pub fn summarise_stacks(stacks: &Vec<VecDeque<char>>) -> String {
+    let mut result = String::new();
+    for stack in stacks {
+        if let Some(c) = stack.front() {
+            result.push(*c);
+        }
+    }
+    result
+}
+
+/// Supply Stacks
+pub struct SupplyStacks;
+
+impl Solution for SupplyStacks {
+    /// The starting stacks and the rearrangement procedure as understood by each crane model
+    type Input = (
+        Vec<VecDeque<char>>,
+        Vec<CrateMover9000Instruction>,
+        Vec<CrateMover9001Instruction>,
+    );
+    type Part1 = String;
+    type Part2 = String;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        let (stacks, part1_instructions) = parse::<CrateMover9000Instruction, R>(reader);
+        let part2_instructions = part1_instructions
+            .iter()
+            .map(CrateMover9001Instruction::from)
+            .collect();
+        Ok((stacks, part1_instructions, part2_instructions))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(&input.0, &input.2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::y2022::day05::{
+        get_part1_input, get_part2_input, CrateMover9000Instruction, CrateMover9001Instruction,
+        Instruction,
+    };
+    use proptest::prelude::*;
+    use std::collections::VecDeque;
+
+    /// Nine stacks of crates, listed from top to bottom
+    fn stacks() -> impl Strategy<Value = Vec<VecDeque<char>>> {
+        prop::collection::vec(
+            prop::collection::vec_deque(prop::char::range('A', 'Z'), 0..8),
+            9,
+        )
+    }
+
+    #[test]
+    fn part1() {
+        let (stacks, instructions) = get_part1_input();
+        let result = super::part1(&stacks, &instructions);
+
+        println!("Part 1: {}", result);
+    }
+
+    #[test]
+    fn part2() {
+        let (stacks, instructions) = get_part2_input();
+        let result = super::part2(&stacks, &instructions);
+
+        println!("Part 2: {}", result);
+    }
+
+    proptest! {
+        #[test]
+        fn crate_mover_9000_instruction_round_trips(
+            count in 1..100usize,
+            from in 0..9usize,
+            to in 0..9usize,
+        ) {
+            let instruction = CrateMover9000Instruction { count, from, to };
+
+            prop_assert_eq!(
+                instruction.to_string().parse::<CrateMover9000Instruction>(),
+                Ok(instruction)
+            );
+        }
+
+        #[test]
+        fn crate_mover_9001_instruction_round_trips(
+            count in 1..100usize,
+            from in 0..9usize,
+            to in 0..9usize,
+        ) {
+            let instruction = CrateMover9001Instruction { count, from, to };
+
+            prop_assert_eq!(
+                instruction.to_string().parse::<CrateMover9001Instruction>(),
+                Ok(instruction)
+            );
+        }
+
+        #[test]
+        fn crate_mover_9001_instruction_converts_from_9000(
+            count in 1..100usize,
+            from in 0..9usize,
+            to in 0..9usize,
+        ) {
+            let instruction = CrateMover9000Instruction { count, from, to };
+
+            prop_assert_eq!(
+                CrateMover9001Instruction::from(&instruction),
+                CrateMover9001Instruction { count, from, to }
+            );
+        }
+
+        #[test]
+        fn crane_models_agree_on_single_crates(
+            stacks in stacks(),
+            from in 0..9usize,
+            to in 0..9usize,
+        ) {
+            prop_assume!(from != to && !stacks[from].is_empty());
+
+            let crate_mover_9000 = CrateMover9000Instruction { count: 1, from, to };
+            let crate_mover_9001 = CrateMover9001Instruction { count: 1, from, to };
+
+            prop_assert_eq!(
+                crate_mover_9000.execute(stacks.clone()),
+                crate_mover_9001.execute(stacks)
+            );
+        }
+    }
+}
@@ -1,11 +1,18 @@
 /// --- Day 6: Tuning Trouble ---
 /// https://adventofcode.com/2022/day/6
-use crate::get_lines;
+use crate::{lines, new_reader, Solution};
 use std::collections::{BTreeSet, VecDeque};
+use std::convert::Infallible;
+use std::io::BufRead;
 
 /// Characters received by the Elves' handheld communication device
 pub fn get_signal() -> String {
-    get_lines("day-06.txt").next().expect("No signal detected")
+    parse(new_reader("2022/day-06.txt"))
+}
+
+/// Read the characters received by the device
+pub fn parse<R: BufRead>(reader: R) -> String {
+    lines(reader).next().expect("No signal detected")
 }
 
 /// Determine which character of the buffer contains the start of a packet
@@ -26,6 +33,16 @@ pub fn get_start_of_message(data_stream: String) -> Result<usize, &'static str>
     get_marker_position(data_stream, 14, "No message found")
 }
 
+/// The number of characters processed before the first start-of-packet marker is detected
+pub fn part1(signal: &str) -> usize {
+    get_start_of_packet(signal.to_string()).unwrap()
+}
+
+/// The number of characters processed before the first start-of-message marker is detected
+pub fn part2(signal: &str) -> usize {
+    get_start_of_message(signal.to_string()).unwrap()
+}
+
 fn get_marker_position(
     data_stream: String,
     distinct_characters: usize,
@@ -47,20 +64,42 @@ fn get_marker_position(
     Err(error)
 }
 
+/// Tuning Trouble
+pub struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::day06::{get_signal, get_start_of_message, get_start_of_packet};
+    use crate::y2022::day06::get_signal;
 
     #[test]
     fn part1() {
-        let result = get_start_of_packet(get_signal()).unwrap();
+        let result = super::part1(&get_signal());
 
         println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let result = get_start_of_message(get_signal()).unwrap();
+        let result = super::part2(&get_signal());
 
         println!("Part 2: {}", result);
     }
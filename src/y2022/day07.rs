@@ -1,9 +1,11 @@
-use crate::day07::Line::{ChangeDirectory, DirectoryListing, FileListing, ListContents};
+use crate::y2022::day07::Line::{ChangeDirectory, DirectoryListing, FileListing, ListContents};
 /// --- Day 7: No Space Left On Device ---
 /// https://adventofcode.com/2022/day/7
-use crate::get_lines;
+use crate::{lines, new_reader, Solution};
 use std::collections::HashMap;
+use std::convert::Infallible;
 use std::fmt::{Debug, Formatter};
+use std::io::BufRead;
 use std::str::FromStr;
 use uuid::Uuid;
 
@@ -110,7 +112,7 @@ impl FileSystem {
             let (items, sub_directory_size) =
                 self.find_directories_smaller_than(sub_directory, max_size);
             total_size += sub_directory_size;
-            list = vec![list, items].concat();
+            list = [list, items].concat();
         }
         for file_id in &parent.files {
             if total_size > max_size {
@@ -137,7 +139,7 @@ impl FileSystem {
             let (items, sub_directory_size) =
                 self.find_directories_larger_than(sub_directory, min_size);
             total_size += sub_directory_size;
-            list = vec![list, items].concat();
+            list = [list, items].concat();
         }
         for file_id in &parent.files {
             let file = self.files.get(file_id).unwrap(); // TODO better error handling
@@ -286,42 +288,79 @@ impl FromStr for Line {
 }
 
 pub fn get_input() -> impl Iterator<Item = Line> {
-    get_lines("day-07.txt")
+    parse(new_reader("2022/day-07.txt"))
+}
+
+/// The sum of the sizes of all the directories with a total size of at most 100,000
+pub fn part1(lines: &[Line]) -> usize {
+    let mut session = Session::default();
+    lines.iter().for_each(|line| line.execute(&mut session));
+    session
+        .find_directories_smaller_than(100_000)
+        .iter()
+        .map(|item| item.1)
+        .sum()
+}
+
+/// The size of the smallest directory that would free up enough space to run the update
+pub fn part2(lines: &[Line]) -> usize {
+    let mut session = Session::default();
+    lines.iter().for_each(|line| line.execute(&mut session));
+    let consumed = session.file_system.consumed_space();
+    let unused = 70_000_000 - consumed;
+    let required = 30_000_000 - unused;
+    session
+        .find_directories_larger_than(required)
+        .iter()
+        .map(|item| item.1)
+        .min()
+        .unwrap()
+}
+
+/// Parse a terminal transcript, one command or output line at a time
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Line> {
+    lines(reader)
         .map(|line| line.parse::<Line>())
         .map(Result::unwrap)
 }
 
+/// No Space Left On Device
+pub struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    type Input = Vec<Line>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::day07::{get_input, Session};
+    use crate::y2022::day07::{get_input, Line};
 
     #[test]
     fn part1() {
-        let mut session = Session::default();
-        get_input().for_each(|line| line.execute(&mut session));
-        let result: usize = session
-            .find_directories_smaller_than(100_000)
-            .iter()
-            .map(|item| item.1)
-            .sum();
+        let result = super::part1(&get_input().collect::<Vec<Line>>());
 
         println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let mut session = Session::default();
-        get_input().for_each(|line| line.execute(&mut session));
-        let consumed = session.file_system.consumed_space();
-        let unused = 70_000_000 - consumed;
-        let required = 30_000_000 - unused;
-        let result: usize = session
-            .find_directories_larger_than(required)
-            .iter()
-            .map(|item| item.1)
-            .min()
-            .unwrap();
+        let result = super::part2(&get_input().collect::<Vec<Line>>());
 
         println!("Part 2: {}", result);
     }
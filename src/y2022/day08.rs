@@ -1,6 +1,8 @@
 /// --- Day 8: Treetop Tree House ---
 /// https://adventofcode.com/2022/day/8
-use crate::get_lines;
+use crate::{lines, new_reader, Solution};
+use std::convert::Infallible;
+use std::io::BufRead;
 
 /// A "peculiar patch of tall trees all planted carefully in a grid" as part of a reforestation
 /// effort.
@@ -122,7 +124,22 @@ impl Forest {
 }
 
 pub fn get_input() -> Forest {
-    let heights = get_lines("day-08.txt")
+    parse(new_reader("2022/day-08.txt"))
+}
+
+/// The number of trees visible from outside the grid
+pub fn part1(forest: &Forest) -> usize {
+    forest.count_visible_trees()
+}
+
+/// The highest scenic score possible for any tree
+pub fn part2(forest: &Forest) -> usize {
+    forest.max_scenic_score()
+}
+
+/// Parse a grid of tree heights
+pub fn parse<R: BufRead>(reader: R) -> Forest {
+    let heights = lines(reader)
         .map(|line| {
             line.chars()
                 .map(|height| height.to_digit(10).expect("Invalid tree height") as u8)
@@ -132,21 +149,43 @@ pub fn get_input() -> Forest {
     Forest { heights }
 }
 
+/// Treetop Tree House
+pub struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    type Input = Forest;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::day08::get_input;
+    use crate::y2022::day08::get_input;
 
     #[test]
     fn part1() {
-        let result = get_input().count_visible_trees();
+        let result = super::part1(&get_input());
 
         println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let result = get_input().max_scenic_score();
+        let result = super::part2(&get_input());
 
         println!("Part 2: {}", result);
     }
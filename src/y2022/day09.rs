@@ -1,8 +1,10 @@
-use crate::day09::Direction::{Down, Left, Right, Up};
-use crate::get_lines;
+use crate::y2022::day09::Direction::{Down, Left, Right, Up};
+use crate::{lines, new_reader, Solution};
 use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+use std::io::BufRead;
 use std::str::FromStr;
 
 /// --- Day 9: Rope Bridge ---
@@ -163,30 +165,71 @@ impl From<usize> for Rope {
 }
 
 pub fn get_input() -> impl Iterator<Item = Instruction> {
-    get_lines("day-09.txt")
+    parse(new_reader("2022/day-09.txt"))
+}
+
+/// The number of positions the tail of a two-knot rope visits at least once
+pub fn part1(instructions: &[Instruction]) -> usize {
+    count_visited(instructions, 2)
+}
+
+/// The number of positions the tail of a ten-knot rope visits at least once
+pub fn part2(instructions: &[Instruction]) -> usize {
+    count_visited(instructions, 10)
+}
+
+fn count_visited(instructions: &[Instruction], num_knots: usize) -> usize {
+    let mut rope: Rope = num_knots.into();
+    instructions
+        .iter()
+        .for_each(|instruction| rope.process(instruction));
+    rope.count_visited()
+}
+
+/// Parse a series of motions of the head of the rope, one per line
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Instruction> {
+    lines(reader)
         .map(|line| line.parse::<Instruction>())
         .map(Result::unwrap)
 }
 
+/// Rope Bridge
+pub struct RopeBridge;
+
+impl Solution for RopeBridge {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::day09::{get_input, Rope};
+    use crate::y2022::day09::{get_input, Instruction};
 
     #[test]
     fn part1() {
-        let mut rope: Rope = 2usize.into();
-        get_input().for_each(|instruction| rope.process(&instruction));
-        let result = rope.count_visited();
+        let result = super::part1(&get_input().collect::<Vec<Instruction>>());
 
         println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let mut rope: Rope = 10usize.into();
-        get_input().for_each(|instruction| rope.process(&instruction));
-        let result = rope.count_visited();
+        let result = super::part2(&get_input().collect::<Vec<Instruction>>());
 
         println!("Part 2: {}", result);
     }
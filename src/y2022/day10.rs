@@ -1,10 +1,13 @@
-use crate::day10::Instruction::{AddX, NoOp};
-use crate::get_lines;
+use crate::answers::Answer;
+use crate::y2022::day10::Instruction::{AddX, NoOp};
+use crate::{lines, new_reader, Solution};
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
+use std::io::BufRead;
 use std::str::FromStr;
 
-/// --- Day 10: Cathode-Ray Tube ---
-/// https://adventofcode.com/2022/day/10
+// --- Day 10: Cathode-Ray Tube ---
+// https://adventofcode.com/2022/day/10
 
 /// The state of the central processing unit in the Elves' handheld device at a given point in time
 pub struct ProcessorState {
@@ -140,6 +143,18 @@ impl Display for HandheldDisplay {
     }
 }
 
+impl From<HandheldDisplay> for Answer {
+    fn from(display: HandheldDisplay) -> Self {
+        Answer::Glyphs(
+            display
+                .pixels
+                .iter()
+                .map(|row| row.iter().collect())
+                .collect(),
+        )
+    }
+}
+
 impl Default for HandheldDisplay {
     fn default() -> Self {
         Self {
@@ -149,45 +164,84 @@ impl Default for HandheldDisplay {
 }
 
 pub fn get_input() -> impl Iterator<Item = Instruction> {
-    get_lines("day-10.txt")
+    parse(new_reader("2022/day-10.txt"))
+}
+
+/// The sum of the signal strengths during the 20th, 60th, 100th, 140th, 180th, and 220th cycles
+pub fn part1(instructions: &[Instruction]) -> i32 {
+    let interesting_cycles = [20u16, 60, 100, 140, 180, 220];
+    let mut state: ProcessorState = Default::default();
+    let mut total_signal_strength = 0;
+    for instruction in instructions {
+        for result in instruction.execute(&state) {
+            if interesting_cycles.contains(&result.cycle) {
+                total_signal_strength += result.signal_strength();
+            }
+            state = result;
+        }
+    }
+    total_signal_strength
+}
+
+/// The image rendered on the CRT by the program
+pub fn part2(instructions: &[Instruction]) -> HandheldDisplay {
+    let mut state: ProcessorState = Default::default();
+    let mut display: HandheldDisplay = Default::default();
+    display.update(&state);
+    for instruction in instructions {
+        for result in instruction.execute(&state) {
+            display.update(&result);
+            state = result;
+        }
+    }
+    display
+}
+
+/// Parse a program for the handheld device, one instruction per line
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = Instruction> {
+    lines(reader)
         .map(|line| line.parse::<Instruction>())
         .map(Result::unwrap)
 }
 
+/// Cathode-Ray Tube
+pub struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    type Input = Vec<Instruction>;
+    type Part1 = i32;
+    type Part2 = HandheldDisplay;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
-    use crate::day10::{get_input, HandheldDisplay, ProcessorState};
+    use crate::y2022::day10::{get_input, Instruction};
 
     #[test]
     fn part1() {
-        let interesting_cycles = vec![20u16, 60, 100, 140, 180, 220];
-        let mut state: ProcessorState = Default::default();
-        let mut total_signal_strength = 0;
-        for instruction in get_input() {
-            for result in instruction.execute(&state) {
-                if interesting_cycles.contains(&result.cycle) {
-                    total_signal_strength += result.signal_strength();
-                }
-                state = result;
-            }
-        }
+        let result = super::part1(&get_input().collect::<Vec<Instruction>>());
 
-        println!("Part 1: {}", total_signal_strength);
+        println!("Part 1: {}", result);
     }
 
     #[test]
     fn part2() {
-        let mut state: ProcessorState = Default::default();
-        let mut display: HandheldDisplay = Default::default();
-        display.update(&state);
-        for instruction in get_input() {
-            for result in instruction.execute(&state) {
-                display.update(&result);
-                state = result;
-            }
-        }
+        let result = super::part2(&get_input().collect::<Vec<Instruction>>());
 
-        println!("Part 2:\n{}", display);
+        println!("Part 2:\n{}", result);
     }
 }
@@ -1,12 +1,14 @@
-use crate::get_block_strings;
+use crate::{blocks, new_reader, Solution};
 use lazy_static::lazy_static;
 use regex::Regex;
+use std::convert::Infallible;
+use std::io::BufRead;
 use std::str::FromStr;
 use Operator::{Add, Multiply};
 use ValueSupplier::{Literal, OldValue};
 
-/// --- Day 11: Monkey in the Middle ---
-/// https://adventofcode.com/2022/day/11
+// --- Day 11: Monkey in the Middle ---
+// https://adventofcode.com/2022/day/11
 
 pub type Int = u64;
 
@@ -201,72 +203,100 @@ impl FromStr for Operation {
 
 /// Parse the descriptions of the monkeys' behaviour. The Monkey's ID is its index in the vector.
 pub fn get_input() -> Vec<Monkey> {
-    get_block_strings("day-11.txt")
+    parse(new_reader("2022/day-11.txt"))
+}
+
+/// The level of monkey business after 20 rounds, with relief after each inspection
+pub fn part1(monkeys: &[Monkey]) -> usize {
+    fn update_worry_level(worry_level: &Int) -> Int {
+        worry_level / 3
+    }
+    monkey_business(monkeys.to_vec(), 20, &update_worry_level)
+}
+
+/// The level of monkey business after 10,000 rounds, without relief
+pub fn part2(monkeys: &[Monkey]) -> usize {
+    let product_of_divisors = monkeys
+        .iter()
+        .map(|monkey| monkey.divisor)
+        .reduce(|x, y| x * y)
+        .expect("No monkeys found");
+    let update_worry_level = move |worry_level: &Int| -> Int { worry_level % product_of_divisors };
+    monkey_business(monkeys.to_vec(), 10_000, &update_worry_level)
+}
+
+/// Simulate the monkeys throwing items and multiply the inspection counts of the two most active
+/// monkeys.
+fn monkey_business(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    update_worry_level: &dyn Fn(&Int) -> Int,
+) -> usize {
+    for _round in 0..rounds {
+        for i in 0..monkeys.len() {
+            let mut monkey = monkeys[i].clone();
+            let throws = monkey.inspect_items(update_worry_level);
+            monkeys[i] = monkey;
+            for throw in throws {
+                let mut target = monkeys[throw.destination_monkey_id].clone();
+                target.items.push(throw.worry_level);
+                monkeys[throw.destination_monkey_id] = target;
+            }
+        }
+    }
+    let mut counts = monkeys
+        .iter()
+        .map(|monkey| monkey.items_inspected)
+        .collect::<Vec<usize>>();
+    counts.sort_unstable_by(|x, y| y.cmp(x));
+    counts[0] * counts[1]
+}
+
+/// Parse the descriptions of the monkeys' behaviour, one block per monkey. The Monkey's ID is its
+/// index in the vector.
+pub fn parse<R: BufRead>(reader: R) -> Vec<Monkey> {
+    blocks(reader)
         .map(|block| block.parse::<Monkey>())
         .map(Result::unwrap)
         .collect()
 }
 
+/// Monkey in the Middle
+pub struct MonkeyInTheMiddle;
+
+impl Solution for MonkeyInTheMiddle {
+    type Input = Vec<Monkey>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
 
-    use crate::day11::{get_input, Int};
+    use crate::y2022::day11::get_input;
 
     #[test]
     pub fn part1() {
-        let mut monkeys = get_input();
-        fn update_worry_level(worry_level: &Int) -> Int {
-            worry_level / 3
-        }
-        for _round in 0..20 {
-            for i in 0..monkeys.len() {
-                let mut monkey = monkeys[i].clone();
-                let throws = monkey.inspect_items(&update_worry_level);
-                monkeys[i] = monkey;
-                for throw in throws {
-                    let mut target = monkeys[throw.destination_monkey_id].clone();
-                    target.items.push(throw.worry_level);
-                    monkeys[throw.destination_monkey_id] = target;
-                }
-            }
-        }
-        let mut counts = monkeys
-            .iter()
-            .map(|monkey| monkey.items_inspected)
-            .collect::<Vec<usize>>();
-        counts.sort_unstable_by(|x, y| y.cmp(x));
-        let result = counts[0] * counts[1];
+        let result = super::part1(&get_input());
         println!("Part 1: {}", result);
     }
 
     #[test]
     pub fn part2() {
-        let mut monkeys = get_input();
-        let product_of_divisors = monkeys
-            .iter()
-            .map(|monkey| monkey.divisor)
-            .reduce(|x, y| x * y)
-            .expect("No monkeys found");
-        let update_worry_level =
-            move |worry_level: &Int| -> Int { worry_level % product_of_divisors };
-        for _round in 0..10_000 {
-            for i in 0..monkeys.len() {
-                let mut monkey = monkeys[i].clone();
-                let throws = monkey.inspect_items(&update_worry_level);
-                monkeys[i] = monkey;
-                for throw in throws {
-                    let mut target = monkeys[throw.destination_monkey_id].clone();
-                    target.items.push(throw.worry_level);
-                    monkeys[throw.destination_monkey_id] = target;
-                }
-            }
-        }
-        let mut counts = monkeys
-            .iter()
-            .map(|monkey| monkey.items_inspected)
-            .collect::<Vec<usize>>();
-        counts.sort_unstable_by(|x, y| y.cmp(x));
-        let result = counts[0] * counts[1];
+        let result = super::part2(&get_input());
         println!("Part 2: {}", result);
     }
 }
@@ -1,14 +1,15 @@
-use crate::get_lines;
+use crate::{lines, new_reader, Solution};
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
+use std::convert::Infallible;
+use std::io::BufRead;
 
-/// --- Day 12: Hill Climbing Algorithm ---
-/// https://adventofcode.com/2022/day/12
+// --- Day 12: Hill Climbing Algorithm ---
+// https://adventofcode.com/2022/day/12
 
 /// A map of the local area in a grid
 pub struct HeightMap {
     grid: Vec<Vec<u8>>,
-    #[allow(dead_code)]
     starting_point: (usize, usize),
     destination: (usize, usize),
 }
@@ -119,9 +120,7 @@ impl PartialEq<Self> for Node {
 
 impl PartialOrd<Self> for Node {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other
-            .estimated_cost_to_destination
-            .partial_cmp(&self.estimated_cost_to_destination)
+        Some(self.cmp(other))
     }
 }
 
@@ -134,7 +133,29 @@ impl Ord for Node {
 }
 
 pub fn get_input() -> HeightMap {
-    let lines = get_lines("day-12.txt")
+    parse(new_reader("2022/day-12.txt"))
+}
+
+/// The fewest steps required to move from the current position to the best signal
+pub fn part1(map: &HeightMap) -> usize {
+    map.length_of_shortest_path(&map.starting_point)
+}
+
+/// The fewest steps required to move from any square with elevation `a` to the best signal
+pub fn part2(map: &HeightMap) -> usize {
+    let mut result = usize::MAX;
+    for potential_trail_head in map.potential_trail_heads() {
+        let distance = map.length_of_shortest_path(&potential_trail_head);
+        if distance < result {
+            result = distance;
+        }
+    }
+    result
+}
+
+/// Parse a grid of elevations including the current position and the destination
+pub fn parse<R: BufRead>(reader: R) -> HeightMap {
+    let lines = lines(reader)
         .map(|line| line.chars().collect::<Vec<char>>())
         .collect::<Vec<Vec<char>>>();
     let mut grid: Vec<Vec<u8>> = vec![vec![]; lines.len()];
@@ -164,28 +185,42 @@ pub fn get_input() -> HeightMap {
     }
 }
 
+/// Hill Climbing Algorithm
+pub struct HillClimbingAlgorithm;
+
+impl Solution for HillClimbingAlgorithm {
+    type Input = HeightMap;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
 
-    use crate::day12::get_input;
+    use crate::y2022::day12::get_input;
 
     #[test]
     pub fn part1() {
-        let map = get_input();
-        let result = map.length_of_shortest_path(&map.starting_point);
+        let result = super::part1(&get_input());
         println!("Part 1: {}", result);
     }
 
     #[test]
     pub fn part2() {
-        let map = get_input();
-        let mut result = usize::MAX;
-        for potential_trail_head in map.potential_trail_heads() {
-            let distance = map.length_of_shortest_path(&potential_trail_head);
-            if distance < result {
-                result = distance;
-            }
-        }
+        let result = super::part2(&get_input());
         println!("Part 2: {}", result);
     }
 }
@@ -0,0 +1,366 @@
+use crate::{blocks, new_reader, Solution};
+use std::cmp::Ordering;
+use std::cmp::Ordering::Equal;
+use std::convert::Infallible;
+use std::fmt::{Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+use PacketItem::{List, Literal};
+
+// --- Day 13: Distress Signal ---
+// https://adventofcode.com/2022/day/13
+
+#[derive(Clone, Debug)]
+pub enum PacketItem {
+    List(Box<Vec<PacketItem>>),
+    Literal(u16),
+}
+
+impl Eq for PacketItem {}
+
+impl PartialEq<Self> for PacketItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Equal
+    }
+}
+
+impl PartialOrd for PacketItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PacketItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self {
+            List(items) => match other {
+                List(other_items) => {
+                    let common_length = items.len().min(other_items.len());
+                    let mut x_iter = items.iter().take(common_length);
+                    let mut y_iter = other_items.iter().take(common_length);
+                    while let (Some(x), Some(y)) = (x_iter.next(), y_iter.next()) {
+                        let result = x.cmp(y);
+                        if result != Equal {
+                            return result;
+                        }
+                    }
+                    items.len().cmp(&other_items.len())
+                }
+                Literal(other_value) => self.cmp(&List(Box::new(vec![Literal(*other_value)]))),
+            },
+            Literal(value) => match other {
+                List(other_items) => {
+                    List(Box::new(vec![Literal(*value)])).cmp(&List(Box::new(other_items.to_vec())))
+                }
+                Literal(other_value) => value.cmp(other_value),
+            },
+        }
+    }
+}
+
+impl Display for PacketItem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            List(items) => {
+                write!(f, "[")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// The reason a line could not be parsed as a packet. Columns start at 1.
+#[derive(Debug, PartialEq, Eq)]
+pub enum PacketParseError {
+    /// A character that is not a digit, a comma or a bracket, or that appears outside the packet
+    UnexpectedCharacter { column: usize, character: char },
+    /// A number that does not fit in a `u16`
+    InvalidNumber { column: usize, number: String },
+    /// A closing bracket without a matching opening bracket
+    UnmatchedBracket { column: usize },
+    /// The line ends before the packet is complete
+    Unterminated,
+}
+
+impl Display for PacketParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter { column, character } => {
+                write!(f, "Unexpected '{}' at column {}", character, column)
+            }
+            Self::InvalidNumber { column, number } => {
+                write!(f, "Invalid number at column {}: {}", column, number)
+            }
+            Self::UnmatchedBracket { column } => {
+                write!(f, "Unmatched closing bracket at column {}", column)
+            }
+            Self::Unterminated => write!(f, "Packet is not terminated"),
+        }
+    }
+}
+
+impl FromStr for PacketItem {
+    type Err = PacketParseError;
+
+    /// Parse a packet, which is always a list at the outermost level
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut stack: Vec<Vec<PacketItem>> = vec![];
+        let mut packet = None;
+
+        // the digits of the number being read and the column of the first one
+        let mut number_buffer = String::new();
+        let mut number_column = 0;
+        for (index, c) in line.chars().enumerate() {
+            let column = index + 1;
+            if packet.is_some() {
+                return Err(PacketParseError::UnexpectedCharacter {
+                    column,
+                    character: c,
+                });
+            }
+            match c {
+                '[' if number_buffer.is_empty() => stack.push(vec![]),
+                '0'..='9' if !stack.is_empty() => {
+                    if number_buffer.is_empty() {
+                        number_column = column;
+                    }
+                    number_buffer.push(c);
+                }
+                ',' if !stack.is_empty() => {
+                    push_number(&mut stack, &mut number_buffer, number_column)?;
+                }
+                ']' => {
+                    push_number(&mut stack, &mut number_buffer, number_column)?;
+                    let completed = stack
+                        .pop()
+                        .ok_or(PacketParseError::UnmatchedBracket { column })?;
+                    let completed = List(Box::new(completed));
+                    match stack.last_mut() {
+                        Some(last) => last.push(completed),
+                        None => packet = Some(completed),
+                    }
+                }
+                _ => {
+                    return Err(PacketParseError::UnexpectedCharacter {
+                        column,
+                        character: c,
+                    })
+                }
+            }
+        }
+
+        packet.ok_or(PacketParseError::Unterminated)
+    }
+}
+
+/// Add the number that has been read so far, if any, to the innermost open list
+fn push_number(
+    stack: &mut [Vec<PacketItem>],
+    number_buffer: &mut String,
+    column: usize,
+) -> Result<(), PacketParseError> {
+    if number_buffer.is_empty() {
+        return Ok(());
+    }
+    let number = number_buffer
+        .parse::<u16>()
+        .map_err(|_| PacketParseError::InvalidNumber {
+            column,
+            number: number_buffer.clone(),
+        })?;
+    number_buffer.clear();
+    if let Some(last) = stack.last_mut() {
+        last.push(Literal(number));
+    }
+    Ok(())
+}
+
+pub fn get_input() -> impl Iterator<Item = (PacketItem, PacketItem)> {
+    parse(new_reader("2022/day-13.txt"))
+}
+
+/// The sum of the indices of the pairs that are already in the right order
+pub fn part1(pairs: &[(PacketItem, PacketItem)]) -> usize {
+    let mut result = 0;
+    for (i, pair) in pairs.iter().enumerate() {
+        if pair.0 < pair.1 {
+            result += i + 1;
+        }
+    }
+    result
+}
+
+/// The decoder key for the distress signal, the product of the indices of the divider packets
+/// once all the packets are sorted
+pub fn part2(pairs: &[(PacketItem, PacketItem)]) -> usize {
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(x, y)| vec![x.clone(), y.clone()])
+        .collect::<Vec<PacketItem>>();
+    packets.sort();
+
+    let divider_x = List(Box::new(vec![List(Box::new(vec![Literal(2)]))]));
+    let divider_y = List(Box::new(vec![List(Box::new(vec![Literal(6)]))]));
+
+    let x_index = packets.binary_search(&divider_x).unwrap_err() + 1;
+    let y_index = packets.binary_search(&divider_y).unwrap_err() + 2;
+    x_index * y_index
+}
+
+/// Parse pairs of packets, one pair per block
+pub fn parse<R: BufRead>(reader: R) -> impl Iterator<Item = (PacketItem, PacketItem)> {
+    blocks(reader).map(|block| -> (PacketItem, PacketItem) {
+        let mut lines = block.split('\n');
+        let left = lines.next().unwrap().parse::<PacketItem>().unwrap();
+        let right = lines.next().unwrap().parse::<PacketItem>().unwrap();
+        (left, right)
+    })
+}
+
+/// Distress Signal
+pub struct DistressSignal;
+
+impl Solution for DistressSignal {
+    type Input = Vec<(PacketItem, PacketItem)>;
+    type Part1 = usize;
+    type Part2 = usize;
+    type Error = Infallible;
+
+    fn parse<R: BufRead>(reader: R) -> Result<Self::Input, Self::Error> {
+        Ok(parse(reader).collect())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+
+    use crate::y2022::day13::PacketItem::{List, Literal};
+    use crate::y2022::day13::{get_input, parse, PacketItem, PacketParseError};
+    use proptest::prelude::*;
+    use std::cmp::Ordering::Equal;
+
+    /// A packet, which is always a list at the outermost level
+    fn packet() -> impl Strategy<Value = PacketItem> {
+        let item = (0..=100u16)
+            .prop_map(Literal)
+            .prop_recursive(4, 32, 5, |inner| {
+                prop::collection::vec(inner, 0..5).prop_map(|items| List(Box::new(items)))
+            });
+        prop::collection::vec(item, 0..5).prop_map(|items| List(Box::new(items)))
+    }
+
+    #[test]
+    pub fn part1() {
+        let result = super::part1(&get_input().collect::<Vec<(PacketItem, PacketItem)>>());
+        println!("Part 1: {}", result);
+    }
+
+    #[test]
+    pub fn part2() {
+        let result = super::part2(&get_input().collect::<Vec<(PacketItem, PacketItem)>>());
+        println!("Part 2: {}", result);
+    }
+
+    #[test]
+    pub fn parse_from_string() {
+        let input = "[1,[2,3]]\n[1,4]\n\n[[]]\n[]\n";
+
+        let pairs = parse(input.as_bytes()).collect::<Vec<(PacketItem, PacketItem)>>();
+
+        assert_eq!(pairs.len(), 2);
+        assert!(pairs[0].0 < pairs[0].1);
+        assert!(pairs[1].0 > pairs[1].1);
+    }
+
+    #[test]
+    pub fn malformed_packets_are_errors() {
+        assert_eq!(
+            "[1,x]".parse::<PacketItem>(),
+            Err(PacketParseError::UnexpectedCharacter {
+                column: 4,
+                character: 'x'
+            })
+        );
+        assert_eq!(
+            "[[70000]]".parse::<PacketItem>(),
+            Err(PacketParseError::InvalidNumber {
+                column: 3,
+                number: "70000".to_string()
+            })
+        );
+        assert_eq!(
+            "[1]]".parse::<PacketItem>(),
+            Err(PacketParseError::UnexpectedCharacter {
+                column: 4,
+                character: ']'
+            })
+        );
+        assert_eq!(
+            "]".parse::<PacketItem>(),
+            Err(PacketParseError::UnmatchedBracket { column: 1 })
+        );
+        assert_eq!(
+            "[[1]".parse::<PacketItem>(),
+            Err(PacketParseError::Unterminated)
+        );
+        assert_eq!(
+            "7".parse::<PacketItem>(),
+            Err(PacketParseError::UnexpectedCharacter {
+                column: 1,
+                character: '7'
+            })
+        );
+        assert_eq!(
+            "".parse::<PacketItem>(),
+            Err(PacketParseError::Unterminated)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn packet_round_trips(packet in packet()) {
+            let parsed = packet.to_string().parse::<PacketItem>().unwrap();
+
+            prop_assert_eq!(&parsed, &packet);
+            prop_assert_eq!(parsed.to_string(), packet.to_string());
+        }
+
+        #[test]
+        fn ordering_is_reflexive_and_antisymmetric(x in packet(), y in packet()) {
+            prop_assert_eq!(x.cmp(&x), Equal);
+            prop_assert_eq!(x.cmp(&y), y.cmp(&x).reverse());
+        }
+
+        #[test]
+        fn ordering_is_transitive(x in packet(), y in packet(), z in packet()) {
+            let permutations = [
+                (&x, &y, &z),
+                (&x, &z, &y),
+                (&y, &x, &z),
+                (&y, &z, &x),
+                (&z, &x, &y),
+                (&z, &y, &x),
+            ];
+            for (a, b, c) in permutations {
+                if a <= b && b <= c {
+                    prop_assert!(a <= c);
+                }
+            }
+        }
+    }
+}
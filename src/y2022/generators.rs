@@ -0,0 +1,362 @@
+//! Seeded generators of valid, arbitrarily large inputs for the 2022 puzzles
+//!
+//! Each generator produces text in the same format as the corresponding puzzle input and always
+//! produces the same text for the same seed and size. They are intended for stress tests and
+//! benchmarks, where the files in `sample/` are too small to be useful.
+use crate::random::Random;
+use std::fmt::Write;
+
+/// Generate an input for a day. The `scale` is the number of records in the input: elves, rounds,
+/// rucksacks, section pairs, rearrangement steps, characters, directories, rope moves,
+/// instructions, items per monkey or packet pairs. For the grids of days 8 and 12 it is the number
+/// of rows. Returns `None` for days without a generator.
+pub fn generate(day: u8, seed: u64, scale: usize) -> Option<String> {
+    let random = &mut Random::new(seed);
+    let scale = scale.max(1);
+    let result = match day {
+        1 => calorie_blocks(random, scale),
+        2 => strategy_guide(random, scale),
+        3 => rucksacks(random, scale.div_ceil(3)),
+        4 => section_pairs(random, scale),
+        5 => crate_diagram(random, scale),
+        6 => data_stream(random, scale),
+        7 => terminal_transcript(random, scale),
+        8 => tree_grid(random, scale, scale),
+        9 => rope_moves(random, scale),
+        10 => cpu_program(random, scale),
+        11 => monkey_specs(random, 8, scale),
+        12 => height_map(random, scale, scale * 5),
+        13 => packet_pairs(random, scale),
+        _ => return None,
+    };
+    Some(result)
+}
+
+/// Day 1: blocks of calorie counts, one block per elf
+pub fn calorie_blocks(random: &mut Random, elves: usize) -> String {
+    let mut result = String::new();
+    for elf in 0..elves {
+        if elf > 0 {
+            result.push('\n');
+        }
+        for _ in 0..random.below(15) + 1 {
+            writeln!(result, "{}", random.between(1_000..=60_000)).unwrap();
+        }
+    }
+    result
+}
+
+/// Day 2: an encrypted strategy guide with one round per line
+pub fn strategy_guide(random: &mut Random, rounds: usize) -> String {
+    let mut result = String::with_capacity(rounds * 4);
+    for _ in 0..rounds {
+        let opponent = random.choose(&['A', 'B', 'C']);
+        let response = random.choose(&['X', 'Y', 'Z']);
+        writeln!(result, "{} {}", opponent, response).unwrap();
+    }
+    result
+}
+
+/// Day 3: rucksack contents for groups of three elves. Each rucksack has exactly one item in both
+/// compartments and each group has exactly one item in common.
+pub fn rucksacks(random: &mut Random, groups: usize) -> String {
+    let mut result = String::new();
+    for _ in 0..groups {
+        let mut items = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".to_vec();
+        random.shuffle(&mut items);
+        let badge = items[0];
+        // every member gets 17 item types of their own so the badge is the only one they share
+        for member in items[1..].chunks(17) {
+            let shared = member[0];
+            let (left_only, right_only) = member[1..].split_at(8);
+            let length = random.below(12) + 2;
+            let mut left = vec![shared, badge];
+            while left.len() < length {
+                left.push(*random.choose(left_only));
+            }
+            let mut right = vec![shared];
+            while right.len() < length {
+                right.push(*random.choose(right_only));
+            }
+            random.shuffle(&mut left);
+            random.shuffle(&mut right);
+            if random.below(2) == 0 {
+                std::mem::swap(&mut left, &mut right);
+            }
+            result.push_str(std::str::from_utf8(&left).unwrap());
+            result.push_str(std::str::from_utf8(&right).unwrap());
+            result.push('\n');
+        }
+    }
+    result
+}
+
+/// Day 4: pairs of section assignments, one pair per line
+pub fn section_pairs(random: &mut Random, pairs: usize) -> String {
+    let mut result = String::new();
+    for _ in 0..pairs {
+        let first_start = random.between(1..=99);
+        let first_end = random.between(first_start..=99);
+        let second_start = random.between(1..=99);
+        let second_end = random.between(second_start..=99);
+        writeln!(
+            result,
+            "{}-{},{}-{}",
+            first_start, first_end, second_start, second_end
+        )
+        .unwrap();
+    }
+    result
+}
+
+/// Day 5: a drawing of nine stacks of crates followed by a rearrangement procedure that never
+/// takes a crate from an empty stack
+pub fn crate_diagram(random: &mut Random, steps: usize) -> String {
+    const STACKS: usize = 9;
+    let stacks = (0..STACKS)
+        .map(|_| {
+            (0..random.below(8) + 1)
+                .map(|_| (b'A' + random.below(26) as u8) as char)
+                .collect::<Vec<char>>()
+        })
+        .collect::<Vec<Vec<char>>>();
+    let mut result = String::new();
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(label) => format!("[{}]", label),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<String>>();
+        writeln!(result, "{}", row.join(" ")).unwrap();
+    }
+    let labels = (1..=STACKS)
+        .map(|label| format!(" {} ", label))
+        .collect::<Vec<String>>();
+    writeln!(result, "{}\n", labels.join(" ")).unwrap();
+
+    let mut heights = stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+    for _ in 0..steps {
+        let occupied = (0..STACKS)
+            .filter(|stack| heights[*stack] > 0)
+            .collect::<Vec<usize>>();
+        let from = *random.choose(&occupied);
+        let to = (from + 1 + random.below(STACKS - 1)) % STACKS;
+        let count = random.below(heights[from].min(10)) + 1;
+        heights[from] -= count;
+        heights[to] += count;
+        writeln!(result, "move {} from {} to {}", count, from + 1, to + 1).unwrap();
+    }
+    result
+}
+
+/// Day 6: a datastream of at least 15 characters. Only the 14 characters before the last one are
+/// guaranteed to form a start-of-message marker, so almost the whole stream must be scanned.
+pub fn data_stream(random: &mut Random, length: usize) -> String {
+    let length = length.max(15);
+    let mut result = (0..length - 15)
+        .map(|_| *random.choose(&['a', 'b', 'c']))
+        .collect::<String>();
+    let mut marker = "abcdefghijklmnopqrstuvwxyz".chars().collect::<Vec<char>>();
+    random.shuffle(&mut marker);
+    result.extend(&marker[..15]);
+    result.push('\n');
+    result
+}
+
+/// Day 7: a terminal transcript that explores every directory of a randomly shaped file system.
+/// The files are sized so that the disk is between 40,000,000 and 70,000,000 bytes full, as
+/// both parts of the puzzle require.
+pub fn terminal_transcript(random: &mut Random, directories: usize) -> String {
+    const TARGET_SIZE: u64 = 50_000_000;
+    let mut children = vec![vec![]; directories];
+    let mut files = vec![vec![]; directories];
+    for directory in 1..directories {
+        children[random.below(directory)].push(directory);
+    }
+    for (directory, files) in files.iter_mut().enumerate() {
+        let minimum = if directory == 0 { 1 } else { 0 };
+        for _ in 0..random.below(5) + minimum {
+            files.push(random.between(1_000..=400_000) as u64);
+        }
+    }
+    let total = files.iter().flatten().sum::<u64>();
+    for size in files.iter_mut().flatten() {
+        *size = (*size * TARGET_SIZE / total).max(1);
+    }
+
+    fn list(directory: usize, children: &[Vec<usize>], files: &[Vec<u64>], result: &mut String) {
+        result.push_str("$ ls\n");
+        for child in &children[directory] {
+            writeln!(result, "dir d{}", child).unwrap();
+        }
+        for (index, size) in files[directory].iter().enumerate() {
+            writeln!(result, "{} f{}.txt", size, index).unwrap();
+        }
+        for child in &children[directory] {
+            writeln!(result, "$ cd d{}", child).unwrap();
+            list(*child, children, files, result);
+            result.push_str("$ cd ..\n");
+        }
+    }
+    let mut result = "$ cd /\n".to_string();
+    list(0, &children, &files, &mut result);
+    result
+}
+
+/// Day 8: a grid of tree heights
+pub fn tree_grid(random: &mut Random, rows: usize, columns: usize) -> String {
+    let mut result = String::with_capacity(rows * (columns + 1));
+    for _ in 0..rows {
+        for _ in 0..columns {
+            result.push((b'0' + random.below(10) as u8) as char);
+        }
+        result.push('\n');
+    }
+    result
+}
+
+/// Day 9: motions of the head of the rope, one per line
+pub fn rope_moves(random: &mut Random, moves: usize) -> String {
+    let mut result = String::new();
+    for _ in 0..moves {
+        let direction = random.choose(&['U', 'D', 'L', 'R']);
+        writeln!(result, "{} {}", direction, random.between(1..=20)).unwrap();
+    }
+    result
+}
+
+/// Day 10: a program for the handheld device that keeps the sprite on or next to the screen. The
+/// device counts cycles in 16 bits, so programs are limited to 30,000 instructions.
+pub fn cpu_program(random: &mut Random, instructions: usize) -> String {
+    let mut result = String::new();
+    let mut register = 1;
+    for _ in 0..instructions.min(30_000) {
+        if random.below(3) == 0 {
+            result.push_str("noop\n");
+        } else {
+            let argument = random.between((-1 - register).max(-10)..=(40 - register).min(10));
+            register += argument;
+            writeln!(result, "addx {}", argument).unwrap();
+        }
+    }
+    result
+}
+
+/// Day 11: between two and eight monkeys, each holding `items` items. The operations only add or
+/// multiply by small factors so that the worry levels in part 1 cannot overflow.
+pub fn monkey_specs(random: &mut Random, monkeys: usize, items: usize) -> String {
+    const DIVISORS: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+    let monkeys = monkeys.clamp(2, DIVISORS.len());
+    let mut result = String::new();
+    for (id, divisor) in DIVISORS.iter().take(monkeys).enumerate() {
+        if id > 0 {
+            result.push('\n');
+        }
+        let starting_items = (0..items)
+            .map(|_| random.between(1..=99).to_string())
+            .collect::<Vec<String>>();
+        let operation = if random.below(2) == 0 {
+            format!("old * {}", random.between(2..=3))
+        } else {
+            format!("old + {}", random.between(1..=9))
+        };
+        let target_if_true = (id + 1 + random.below(monkeys - 1)) % monkeys;
+        let target_if_false = (id + 1 + random.below(monkeys - 1)) % monkeys;
+        writeln!(result, "Monkey {}:", id).unwrap();
+        writeln!(result, "  Starting items: {}", starting_items.join(", ")).unwrap();
+        writeln!(result, "  Operation: new = {}", operation).unwrap();
+        writeln!(result, "  Test: divisible by {}", divisor).unwrap();
+        writeln!(result, "    If true: throw to monkey {}", target_if_true).unwrap();
+        writeln!(result, "    If false: throw to monkey {}", target_if_false).unwrap();
+    }
+    result
+}
+
+/// Day 12: a height map that climbs steadily from west to east. Pits that can be entered but not
+/// climbed out of are scattered through it, away from the northern edge so that the destination
+/// always remains reachable.
+pub fn height_map(random: &mut Random, rows: usize, columns: usize) -> String {
+    let columns = columns.max(26);
+    let mut result = String::with_capacity(rows * (columns + 1));
+    for row in 0..rows {
+        for column in 0..columns {
+            let height = if row == rows / 2 && column == 0 {
+                'S'
+            } else if row == rows / 2 && column == columns - 1 {
+                'E'
+            } else if row > 0 && column < columns - 1 && random.below(8) == 0 {
+                'a'
+            } else {
+                (b'a' + (column * 26 / columns) as u8) as char
+            };
+            result.push(height);
+        }
+        result.push('\n');
+    }
+    result
+}
+
+/// Day 13: pairs of packets separated by blank lines. Every packet has at least two elements so
+/// that none of them are equal to a divider packet.
+pub fn packet_pairs(random: &mut Random, pairs: usize) -> String {
+    fn packet(random: &mut Random, depth: usize, minimum_length: usize, result: &mut String) {
+        result.push('[');
+        for index in 0..random.below(5) + minimum_length {
+            if index > 0 {
+                result.push(',');
+            }
+            if depth < 4 && random.below(3) == 0 {
+                packet(random, depth + 1, 0, result);
+            } else {
+                write!(result, "{}", random.below(11)).unwrap();
+            }
+        }
+        result.push(']');
+    }
+    let mut result = String::new();
+    for pair in 0..pairs {
+        if pair > 0 {
+            result.push('\n');
+        }
+        for _ in 0..2 {
+            packet(random, 0, 2, &mut result);
+            result.push('\n');
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::y2022::generators::generate;
+    use crate::y2022::solutions;
+
+    #[test]
+    fn every_day_can_be_solved() {
+        for (day, puzzle) in solutions() {
+            for seed in 0..4 {
+                let input = generate(day, seed, 12).unwrap();
+
+                let parsed = puzzle.parse(&mut input.as_bytes()).unwrap();
+                parsed.part1();
+                parsed.part2();
+            }
+        }
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in solutions().keys() {
+            assert_eq!(generate(*day, 13, 20), generate(*day, 13, 20));
+            assert_ne!(generate(*day, 13, 20), generate(*day, 14, 20));
+        }
+    }
+
+    #[test]
+    fn unknown_days_are_not_generated() {
+        assert!(generate(26, 0, 10).is_none());
+    }
+}
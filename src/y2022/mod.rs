@@ -0,0 +1,37 @@
+//! Solutions to the 2022 Advent of Code puzzles
+//! https://adventofcode.com/2022
+use crate::Calendar;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod generators;
+
+/// Every available 2022 solution keyed by day number
+pub fn solutions() -> Calendar {
+    let mut result: Calendar = Calendar::new();
+    result.insert(1, &day01::CalorieCounting);
+    result.insert(2, &day02::RockPaperScissors);
+    result.insert(3, &day03::RucksackReorganization);
+    result.insert(4, &day04::CampCleanup);
+    result.insert(5, &day05::SupplyStacks);
+    result.insert(6, &day06::TuningTrouble);
+    result.insert(7, &day07::NoSpaceLeftOnDevice);
+    result.insert(8, &day08::TreetopTreeHouse);
+    result.insert(9, &day09::RopeBridge);
+    result.insert(10, &day10::CathodeRayTube);
+    result.insert(11, &day11::MonkeyInTheMiddle);
+    result.insert(12, &day12::HillClimbingAlgorithm);
+    result.insert(13, &day13::DistressSignal);
+    result
+}